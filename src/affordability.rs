@@ -0,0 +1,152 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::{Cost, Scenario};
+use crate::schedule::simulate;
+
+/// The borrower's side of an affordability check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AffordabilityInputs {
+    pub gross_annual_income: f64,
+    /// Car, student loan, card and other monthly debt payments.
+    pub monthly_debts: f64,
+    /// Maximum housing payment as a fraction of gross monthly income.
+    pub front_end_dti: f64,
+    /// Maximum housing payment plus other debts as a fraction of gross monthly income.
+    pub back_end_dti: f64,
+    /// Cash on hand for the down payment and closing costs.
+    pub available_cash: f64,
+}
+
+impl Default for AffordabilityInputs {
+    fn default() -> Self {
+        Self {
+            gross_annual_income: 0.0,
+            monthly_debts: 0.0,
+            front_end_dti: 0.28,
+            back_end_dti: 0.36,
+            available_cash: 0.0,
+        }
+    }
+}
+
+/// Limit that caps the purchase price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BindingConstraint {
+    FrontEndDti,
+    BackEndDti,
+    Cash,
+}
+
+impl fmt::Display for BindingConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BindingConstraint::FrontEndDti => "front-end DTI",
+            BindingConstraint::BackEndDti => "back-end DTI",
+            BindingConstraint::Cash => "cash to close",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Affordability {
+    pub max_price: f64,
+    pub loan_amount: f64,
+    /// First month's principal, interest, property tax, insurance, HOA and mortgage
+    /// insurance at the maximum price.
+    pub housing_payment: f64,
+    pub front_end_ratio: f64,
+    pub back_end_ratio: f64,
+    pub cash_to_close: f64,
+    pub binding: BindingConstraint,
+}
+
+/// Highest price the solver will consider.
+const MAX_PRICE: f64 = 1e9;
+
+/// First-month housing payment a lender counts towards DTI; maintenance is excluded.
+fn housing_payment(scenario: &Scenario) -> f64 {
+    simulate(scenario).rows.first().map_or(0.0, |row| {
+        row.required_payment + row.taxes + row.insurance + row.hoa + row.pmi
+    })
+}
+
+/// Largest price in `[floor, MAX_PRICE]`, to the cent, at which `fits` holds, assuming it
+/// holds below some price and not above it. `None` when it holds at no price; `MAX_PRICE`
+/// when it never stops holding.
+fn max_fitting_price(floor: f64, fits: impl Fn(f64) -> bool) -> Option<f64> {
+    let mut low = floor;
+    if !fits(low) {
+        return None;
+    }
+    let mut high = low;
+    while fits(high) {
+        if high >= MAX_PRICE {
+            return Some(MAX_PRICE);
+        }
+        low = high;
+        high = (high * 2.0).min(MAX_PRICE);
+    }
+    while high - low > 0.01 {
+        let mid = (low + high) / 2.0;
+        if fits(mid) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some(low)
+}
+
+/// Solves for the highest purchase price under `scenario`'s down payment, loan and cost
+/// assumptions that keeps both DTI ratios and the cash to close within `inputs`. Taxes,
+/// insurance, HOA and mortgage insurance follow the price as they do in the schedule.
+///
+/// Returns the constraint that no price can meet as the error, such as debts already
+/// above the back-end ratio or a fixed down payment larger than the cash available.
+pub fn solve_affordability(
+    scenario: &Scenario,
+    inputs: &AffordabilityInputs,
+) -> Result<Affordability, BindingConstraint> {
+    let at = |price: f64| Scenario {
+        house_value: price,
+        ..scenario.clone()
+    };
+    let monthly_income = inputs.gross_annual_income / 12.0;
+    // A fixed down payment only makes sense on a price at least that large
+    let floor = match scenario.down_payment {
+        Cost::Fixed(amount) => amount.max(1.0),
+        Cost::Rate(_) => 1.0,
+    };
+
+    let constraints = [
+        (BindingConstraint::FrontEndDti, inputs.front_end_dti * monthly_income),
+        (BindingConstraint::BackEndDti, inputs.back_end_dti * monthly_income - inputs.monthly_debts),
+    ];
+    let mut limits = Vec::with_capacity(3);
+    for (constraint, budget) in constraints {
+        let price = max_fitting_price(floor, |price| housing_payment(&at(price)) <= budget).ok_or(constraint)?;
+        limits.push((constraint, price));
+    }
+    let cash = max_fitting_price(floor, |price| at(price).cash_to_close() <= inputs.available_cash)
+        .ok_or(BindingConstraint::Cash)?;
+    limits.push((BindingConstraint::Cash, cash));
+
+    let (binding, max_price) = limits
+        .into_iter()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("three constraints");
+    let scenario = at(max_price);
+    let housing_payment = housing_payment(&scenario);
+
+    Ok(Affordability {
+        max_price,
+        loan_amount: scenario.loan_amount(),
+        housing_payment,
+        front_end_ratio: housing_payment / monthly_income,
+        back_end_ratio: (housing_payment + inputs.monthly_debts) / monthly_income,
+        cash_to_close: scenario.cash_to_close(),
+        binding,
+    })
+}
@@ -0,0 +1,110 @@
+use std::io::{self, BufRead};
+
+use serde::{Deserialize, Serialize};
+
+/// Path of the index an adjustable rate is pegged to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IndexPath {
+    Constant(f64),
+    /// `(month, rate)` steps sorted by month; each rate holds until the next step.
+    Stepped(Vec<(u32, f64)>),
+}
+
+impl IndexPath {
+    /// Reads a stepped path from `month,rate` lines with the rate in percent, as index
+    /// values are usually published. Blank lines and a non-numeric header are skipped.
+    pub fn from_csv<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut steps = Vec::new();
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let parsed = line.split_once(',').and_then(|(month, rate)| {
+                Some((month.trim().parse::<u32>().ok()?, rate.trim().parse::<f64>().ok()?))
+            });
+            match parsed {
+                Some((month, rate)) => steps.push((month, rate / 100.0)),
+                None if line_number == 0 => continue,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("line {}: expected `month,rate`, got `{}`", line_number + 1, line),
+                    ))
+                }
+            }
+        }
+        if steps.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "index CSV has no rows"));
+        }
+        steps.sort_by_key(|&(month, _)| month);
+        Ok(IndexPath::Stepped(steps))
+    }
+
+    pub fn rate_at(&self, month: u32) -> f64 {
+        match self {
+            IndexPath::Constant(rate) => *rate,
+            IndexPath::Stepped(steps) => steps
+                .iter()
+                .take_while(|&&(start, _)| start <= month)
+                .last()
+                .or(steps.first())
+                .map_or(0.0, |&(_, rate)| rate),
+        }
+    }
+}
+
+/// Adjustable-rate terms. The scenario's `interest_rate` is the initial (teaser) rate.
+///
+/// Caps and floors are annual fractions; `lifetime_cap` is measured above the initial rate
+/// and `floor` is an absolute minimum rate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Arm {
+    pub initial_period_months: u32,
+    pub adjustment_period_months: u32,
+    pub index: IndexPath,
+    pub margin: f64,
+    pub initial_cap: f64,
+    pub periodic_cap: f64,
+    pub lifetime_cap: f64,
+    pub floor: f64,
+}
+
+impl Arm {
+    /// A hybrid ARM such as a 5/1, 7/6 or 10/6, with the customary caps for its
+    /// adjustment period (2/2/5 for annual resets, 5/1/5 for six-month resets) and the
+    /// margin as the floor.
+    pub fn hybrid(fixed_years: u32, adjustment_months: u32, index: IndexPath, margin: f64) -> Self {
+        let (initial_cap, periodic_cap) = if adjustment_months >= 12 { (0.02, 0.02) } else { (0.05, 0.01) };
+        Self {
+            initial_period_months: fixed_years * 12,
+            adjustment_period_months: adjustment_months,
+            index,
+            margin,
+            initial_cap,
+            periodic_cap,
+            lifetime_cap: 0.05,
+            floor: margin,
+        }
+    }
+
+    pub fn is_reset_month(&self, month: u32) -> bool {
+        month > self.initial_period_months
+            && (month - self.initial_period_months - 1).is_multiple_of(self.adjustment_period_months.max(1))
+    }
+
+    /// Rate taking effect at a reset `month`, given the rate in force before it.
+    pub fn adjusted_rate(&self, month: u32, current_rate: f64, initial_rate: f64) -> f64 {
+        let fully_indexed = self.index.rate_at(month) + self.margin;
+        let cap = if month == self.initial_period_months + 1 {
+            self.initial_cap
+        } else {
+            self.periodic_cap
+        };
+        fully_indexed
+            .clamp(current_rate - cap, current_rate + cap)
+            .min(initial_rate + self.lifetime_cap)
+            .max(self.floor)
+    }
+}
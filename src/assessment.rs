@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// How the taxing authority values the home for a percentage property tax.
+///
+/// The default assesses at market value every month. A growth cap, a reassessment
+/// cycle or a senior freeze switches to an assessment that starts at the purchase price
+/// and changes only on purchase anniversaries. None of this applies to a fixed tax bill.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Assessment {
+    /// Maximum annual growth of the assessed value, e.g. `0.02` under California's Prop 13.
+    pub annual_cap: Option<f64>,
+    /// Reassess only every this many years instead of every anniversary.
+    pub reassessment_years: Option<u32>,
+    /// Amount subtracted from the assessed value before the rate applies.
+    pub homestead_exemption: f64,
+    /// Year of ownership from which the assessed value stops changing, such as when the
+    /// owner qualifies for a senior freeze.
+    pub freeze_year: Option<u32>,
+}
+
+impl Assessment {
+    fn tracks_market(&self) -> bool {
+        self.annual_cap.is_none() && self.reassessment_years.is_none() && self.freeze_year.is_none()
+    }
+
+    /// Assessed value for `month`, given last month's assessment (the purchase price
+    /// before month 1) and the current market value.
+    pub(crate) fn assessed_value(&self, month: u32, market_value: f64, previous: f64) -> f64 {
+        if self.tracks_market() {
+            return market_value;
+        }
+        if month <= 1 || !(month - 1).is_multiple_of(12) {
+            return previous;
+        }
+
+        let year = (month - 1) / 12;
+        if self.freeze_year.is_some_and(|freeze_year| year >= freeze_year) {
+            return previous;
+        }
+        let years_since_assessment = match self.reassessment_years {
+            Some(cycle) if cycle > 1 && !year.is_multiple_of(cycle) => return previous,
+            Some(cycle) => cycle.max(1),
+            None => 1,
+        };
+        match self.annual_cap {
+            Some(cap) => market_value.min(previous * (1.0 + cap).powi(years_since_assessment as i32)),
+            None => market_value,
+        }
+    }
+
+    /// Value the tax rate applies to once the exemption is taken.
+    pub(crate) fn taxable_value(&self, assessed_value: f64) -> f64 {
+        (assessed_value - self.homestead_exemption).max(0.0)
+    }
+}
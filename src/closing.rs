@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClosingCostItem {
+    pub name: String,
+    pub amount: f64,
+}
+
+/// Up-front cash beyond the down payment, and the rate trade-offs bought with it.
+///
+/// Points are percent of the loan amount: each discount point costs 1% of the loan and
+/// lowers the rate by `rate_change_per_point`; each point of lender credit pays 1% of
+/// the loan toward closing and raises the rate by the same step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClosingCosts {
+    pub items: Vec<ClosingCostItem>,
+    pub discount_points: f64,
+    pub lender_credit_points: f64,
+    pub rate_change_per_point: f64,
+    /// Seller-paid closing costs.
+    pub seller_concessions: f64,
+}
+
+impl Default for ClosingCosts {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            discount_points: 0.0,
+            lender_credit_points: 0.0,
+            rate_change_per_point: 0.0025,
+            seller_concessions: 0.0,
+        }
+    }
+}
+
+impl ClosingCosts {
+    pub fn itemized_total(&self) -> f64 {
+        self.items.iter().map(|item| item.amount).sum()
+    }
+
+    /// Change to the note rate from points and credits; negative when buying down.
+    pub fn rate_adjustment(&self) -> f64 {
+        (self.lender_credit_points - self.discount_points) * self.rate_change_per_point
+    }
+
+    pub fn points_cost(&self, loan_amount: f64) -> f64 {
+        loan_amount * self.discount_points / 100.0
+    }
+
+    pub fn lender_credit(&self, loan_amount: f64) -> f64 {
+        loan_amount * self.lender_credit_points / 100.0
+    }
+
+    /// What the buyer pays at closing beyond the down payment. Credits and concessions
+    /// can cover closing costs but never pay out cash.
+    pub fn net_cost(&self, loan_amount: f64) -> f64 {
+        (self.itemized_total() + self.points_cost(loan_amount)
+            - self.lender_credit(loan_amount)
+            - self.seller_concessions)
+            .max(0.0)
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Annual escalation for each fixed-dollar cost line, applied on purchase anniversaries.
+/// A line left at `None` follows the scenario's general inflation rate. Lines entered as a
+/// percentage of home value or loan balance already move with those and are not escalated.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Escalation {
+    pub hoa: Option<f64>,
+    pub property_tax: Option<f64>,
+    pub insurance: Option<f64>,
+    pub maintenance: Option<f64>,
+    pub pmi: Option<f64>,
+}
+
+impl Escalation {
+    /// Multiplier on a line's year-one amount after `years` anniversaries.
+    pub(crate) fn factor(line: Option<f64>, inflation_rate: f64, years: u32) -> f64 {
+        (1.0 + line.unwrap_or(inflation_rate)).powi(years as i32)
+    }
+}
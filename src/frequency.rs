@@ -0,0 +1,116 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::Scenario;
+use crate::schedule::{amortizing_payment, simulate};
+
+/// How often the borrower pays. Each period accrues interest at the annual rate divided
+/// by the periods per year; the schedule still reports one row per month.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentFrequency {
+    #[default]
+    Monthly,
+    /// Twice a month, 24 payments a year.
+    SemiMonthly,
+    /// Every two weeks, 26 payments a year, amortized over the term.
+    Biweekly,
+    /// Half the monthly payment every two weeks: 13 monthly payments a year, so the loan
+    /// pays off early.
+    AcceleratedBiweekly,
+    /// Every week, 52 payments a year, amortized over the term.
+    Weekly,
+}
+
+impl PaymentFrequency {
+    pub const ALL: [PaymentFrequency; 5] = [
+        PaymentFrequency::Monthly,
+        PaymentFrequency::SemiMonthly,
+        PaymentFrequency::Biweekly,
+        PaymentFrequency::AcceleratedBiweekly,
+        PaymentFrequency::Weekly,
+    ];
+
+    pub fn periods_per_year(self) -> u32 {
+        match self {
+            PaymentFrequency::Monthly => 12,
+            PaymentFrequency::SemiMonthly => 24,
+            PaymentFrequency::Biweekly | PaymentFrequency::AcceleratedBiweekly => 26,
+            PaymentFrequency::Weekly => 52,
+        }
+    }
+
+    /// Payment periods that fall in `month`, numbered from 1.
+    pub(crate) fn periods_in_month(self, month: u32) -> RangeInclusive<u32> {
+        let per_year = self.periods_per_year();
+        ((month - 1) * per_year / 12 + 1)..=(month * per_year / 12)
+    }
+
+    /// Number of the last period in a term of `months`.
+    pub(crate) fn last_period(self, months: u32) -> u32 {
+        months * self.periods_per_year() / 12
+    }
+
+    /// Payment per period that retires `principal` over what is left of the term.
+    pub(crate) fn payment(self, principal: f64, annual_rate: f64, remaining_months: u32, remaining_periods: u32) -> f64 {
+        match self {
+            PaymentFrequency::AcceleratedBiweekly => {
+                amortizing_payment(principal, annual_rate / 12.0, remaining_months as f64) / 2.0
+            }
+            _ => amortizing_payment(
+                principal,
+                annual_rate / self.periods_per_year() as f64,
+                remaining_periods as f64,
+            ),
+        }
+    }
+}
+
+impl fmt::Display for PaymentFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PaymentFrequency::Monthly => "Monthly",
+            PaymentFrequency::SemiMonthly => "Semi-monthly",
+            PaymentFrequency::Biweekly => "Biweekly",
+            PaymentFrequency::AcceleratedBiweekly => "Accelerated biweekly",
+            PaymentFrequency::Weekly => "Weekly",
+        })
+    }
+}
+
+/// Payoff under one payment frequency, and what it saves against paying monthly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrequencyOutcome {
+    pub frequency: PaymentFrequency,
+    pub months_to_payoff: u32,
+    pub total_interest_paid: f64,
+    pub months_saved: i64,
+    pub interest_saved: f64,
+}
+
+/// Runs `scenario` under every payment frequency, in the order of [`PaymentFrequency::ALL`].
+pub fn compare_frequencies(scenario: &Scenario) -> Vec<FrequencyOutcome> {
+    let summaries: Vec<_> = PaymentFrequency::ALL
+        .iter()
+        .map(|&payment_frequency| {
+            let schedule = simulate(&Scenario {
+                payment_frequency,
+                ..scenario.clone()
+            });
+            (payment_frequency, schedule.summary)
+        })
+        .collect();
+    let monthly = &summaries[0].1;
+
+    summaries
+        .iter()
+        .map(|(frequency, summary)| FrequencyOutcome {
+            frequency: *frequency,
+            months_to_payoff: summary.months_to_payoff,
+            total_interest_paid: summary.total_interest_paid,
+            months_saved: monthly.months_to_payoff as i64 - summary.months_to_payoff as i64,
+            interest_saved: monthly.total_interest_paid - summary.total_interest_paid,
+        })
+        .collect()
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::{Cost, Scenario};
+use crate::schedule::{simulate, Schedule};
+
+/// Outcome of the schedule to hit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GoalOutput {
+    /// Actual payment in the first month.
+    FirstPayment,
+    /// Total waste cost after any income tax benefit.
+    TotalWasteCost,
+    PayoffMonth,
+    /// Equity at the end of the given year, or at payoff if that comes first.
+    EquityAtYear(u32),
+}
+
+impl GoalOutput {
+    pub fn of(self, schedule: &Schedule) -> f64 {
+        match self {
+            GoalOutput::FirstPayment => schedule.rows.first().map_or(0.0, |row| row.actual_payment),
+            GoalOutput::TotalWasteCost => schedule.summary.total_after_tax_waste_cost,
+            GoalOutput::PayoffMonth => schedule.summary.months_to_payoff as f64,
+            GoalOutput::EquityAtYear(year) => schedule
+                .equity_at(year * 12)
+                .map(|snapshot| snapshot.equity)
+                .or(schedule.rows.last().map(|row| row.equity))
+                .unwrap_or(0.0),
+        }
+    }
+}
+
+impl fmt::Display for GoalOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GoalOutput::FirstPayment => f.write_str("first month's payment"),
+            GoalOutput::TotalWasteCost => f.write_str("total waste cost"),
+            GoalOutput::PayoffMonth => f.write_str("payoff month"),
+            GoalOutput::EquityAtYear(year) => write!(f, "equity at year {}", year),
+        }
+    }
+}
+
+/// Input the solver varies, over the range it searches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GoalInput {
+    /// Dollar down payment, from nothing to a dollar short of the price so there is
+    /// still a loan to schedule.
+    DownPayment,
+    /// Extra principal each month, from nothing to the loan amount.
+    ExtraPrincipal,
+    /// Interest rate before points and credits, from 0% to 30%.
+    InterestRate,
+    /// Purchase price, up to $100M.
+    Price,
+}
+
+impl GoalInput {
+    pub const ALL: [GoalInput; 4] = [
+        GoalInput::DownPayment,
+        GoalInput::ExtraPrincipal,
+        GoalInput::InterestRate,
+        GoalInput::Price,
+    ];
+
+    fn range(self, scenario: &Scenario) -> (f64, f64) {
+        match self {
+            GoalInput::DownPayment => (0.0, scenario.house_value - 1.0),
+            GoalInput::ExtraPrincipal => (0.0, scenario.loan_amount()),
+            GoalInput::InterestRate => (0.0, 0.30),
+            GoalInput::Price => (1.0, 1e8),
+        }
+    }
+
+    /// Smallest step worth telling apart: a cent, or a thousandth of a basis point.
+    fn tolerance(self) -> f64 {
+        match self {
+            GoalInput::InterestRate => 1e-7,
+            _ => 0.01,
+        }
+    }
+
+    fn apply(self, scenario: &Scenario, value: f64) -> Scenario {
+        let mut scenario = scenario.clone();
+        match self {
+            GoalInput::DownPayment => scenario.down_payment = Cost::Fixed(value),
+            GoalInput::ExtraPrincipal => scenario.extra_principal = value,
+            GoalInput::InterestRate => scenario.interest_rate = value,
+            GoalInput::Price => scenario.house_value = value,
+        }
+        scenario
+    }
+}
+
+impl fmt::Display for GoalInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GoalInput::DownPayment => "down payment",
+            GoalInput::ExtraPrincipal => "extra principal",
+            GoalInput::InterestRate => "interest rate",
+            GoalInput::Price => "purchase price",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalSeek {
+    /// Input value found; a rate is an annual fraction.
+    pub input_value: f64,
+    /// Output the schedule reaches with that input, as close to the target as the
+    /// output allows (payoff month moves in whole months).
+    pub achieved: f64,
+}
+
+/// The target lies outside what the input can reach over its whole range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GoalSeekError {
+    pub input: GoalInput,
+    pub output: GoalOutput,
+    pub target: f64,
+    /// Output at the bottom and top of the input's range.
+    pub lowest_input_gives: f64,
+    pub highest_input_gives: f64,
+}
+
+impl fmt::Display for GoalSeekError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no {} reaches a {} of {:.2}: across its range the {} only goes from {:.2} to {:.2}",
+            self.input, self.output, self.target, self.output, self.lowest_input_gives, self.highest_input_gives
+        )
+    }
+}
+
+impl std::error::Error for GoalSeekError {}
+
+/// Bisects `input` over its range until `output` of the schedule reaches `target`.
+///
+/// The output is assumed to move one way as the input rises, as all the supported pairs
+/// do. A stepped output such as the payoff month settles on the smallest input that
+/// reaches the target; when the target falls between two steps, on the input giving the
+/// closer output.
+pub fn goal_seek(
+    scenario: &Scenario,
+    input: GoalInput,
+    output: GoalOutput,
+    target: f64,
+) -> Result<GoalSeek, GoalSeekError> {
+    let evaluate = |value: f64| output.of(&simulate(&input.apply(scenario, value))) - target;
+
+    let (mut low, mut high) = input.range(scenario);
+    let mut low_gap = evaluate(low);
+    let mut high_gap = evaluate(high);
+    if low_gap.signum() == high_gap.signum() && low_gap != 0.0 && high_gap != 0.0 {
+        return Err(GoalSeekError {
+            input,
+            output,
+            target,
+            lowest_input_gives: low_gap + target,
+            highest_input_gives: high_gap + target,
+        });
+    }
+
+    if low_gap == 0.0 {
+        high = low;
+        high_gap = low_gap;
+    }
+
+    // Keep the target strictly on the high side, so a stepped output settles on the
+    // smallest input that reaches it
+    while high - low > input.tolerance() {
+        let mid = (low + high) / 2.0;
+        let gap = evaluate(mid);
+        if gap != 0.0 && gap.signum() == low_gap.signum() {
+            low = mid;
+            low_gap = gap;
+        } else {
+            high = mid;
+            high_gap = gap;
+        }
+    }
+
+    let (input_value, gap) = if high_gap.abs() <= low_gap.abs() { (high, high_gap) } else { (low, low_gap) };
+    Ok(GoalSeek {
+        input_value,
+        achieved: gap + target,
+    })
+}
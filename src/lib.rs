@@ -0,0 +1,50 @@
+//! Mortgage amortization and true-cost-of-ownership engine.
+//!
+//! Build a [`Scenario`] from already-parsed inputs and pass it to [`simulate`] to get a
+//! month-by-month [`Schedule`] of [`MortgageRow`]s plus a [`MortgageSummary`].
+
+mod affordability;
+mod arm;
+mod assessment;
+mod closing;
+mod escalation;
+mod frequency;
+mod goal;
+mod lien;
+mod money;
+mod montecarlo;
+mod pmi;
+mod prepayment;
+mod program;
+mod real;
+mod refinance;
+mod rental;
+mod rent;
+mod sale;
+mod scenario;
+mod sensitivity;
+mod schedule;
+mod tax;
+
+pub use affordability::{solve_affordability, Affordability, AffordabilityInputs, BindingConstraint};
+pub use arm::{Arm, IndexPath};
+pub use assessment::Assessment;
+pub use closing::{ClosingCostItem, ClosingCosts};
+pub use escalation::Escalation;
+pub use frequency::{compare_frequencies, FrequencyOutcome, PaymentFrequency};
+pub use goal::{goal_seek, GoalInput, GoalOutput, GoalSeek, GoalSeekError};
+pub use lien::{SecondLien, SecondLienKind};
+pub use money::Rounding;
+pub use montecarlo::{run_monte_carlo, Factor, MonteCarlo, MonteCarloAssumptions, Percentiles};
+pub use pmi::{PmiCancellation, PmiDropOff};
+pub use prepayment::{compare_prepayments, Prepayment, PrepaymentComparison, PrepaymentOutcome};
+pub use program::LoanProgram;
+pub use real::PresentValues;
+pub use refinance::{analyze_refinance, Refinance, RefinanceAnalysis};
+pub use rental::RentalUnit;
+pub use rent::{compare_rent, RentComparison, RentRow, RentScenario};
+pub use sale::{analyze_sale, irr, SaleAnalysis, SaleAssumptions};
+pub use scenario::{Cost, InvalidScenario, LoanTerm, Scenario};
+pub use sensitivity::{analyze_sensitivity, Sensitivity, SensitivityAnalysis, SensitivityMetric, SensitivityOutcome};
+pub use schedule::{simulate, EquitySnapshot, MortgageRow, MortgageSummary, Schedule};
+pub use tax::{FilingStatus, TaxProfile};
@@ -0,0 +1,132 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::arm::IndexPath;
+use crate::money::{decimal, to_f64, Rounding};
+use crate::scenario::{Cost, LoanTerm};
+use crate::schedule::amortizing_payment;
+
+/// How a second lien is priced and repaid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SecondLienKind {
+    /// Closed-end second mortgage at a fixed rate, amortizing over its term.
+    Fixed { rate: f64 },
+    /// Home equity line of credit at the index plus a margin, adjusting monthly. Only
+    /// interest is due through the draw period; the balance then amortizes over the rest
+    /// of the term.
+    Heloc {
+        index: IndexPath,
+        margin: f64,
+        draw_months: u32,
+    },
+}
+
+/// Second mortgage taken out alongside the first at purchase, as in an 80/10/10.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecondLien {
+    /// Rate of the purchase price, or fixed dollars.
+    pub amount: Cost,
+    pub term: LoanTerm,
+    pub kind: SecondLienKind,
+}
+
+impl SecondLien {
+    /// Annual rate charged in `month`.
+    pub fn rate_at(&self, month: u32) -> f64 {
+        match &self.kind {
+            SecondLienKind::Fixed { rate } => *rate,
+            SecondLienKind::Heloc { index, margin, .. } => (index.rate_at(month) + margin).max(0.0),
+        }
+    }
+
+    /// Months at the start of the term in which only interest is due.
+    fn interest_only_months(&self) -> u32 {
+        match self.kind {
+            SecondLienKind::Fixed { .. } => 0,
+            SecondLienKind::Heloc { draw_months, .. } => draw_months,
+        }
+    }
+}
+
+/// Interest, principal and any balloon paid on the second lien in one month, and the rate
+/// charged.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct LienPayment {
+    pub(crate) interest: Decimal,
+    pub(crate) principal: Decimal,
+    pub(crate) balloon: Decimal,
+    pub(crate) rate: f64,
+}
+
+/// Running balance of a second lien, paid monthly whatever the first lien's frequency.
+pub(crate) struct SecondLienLoan<'a> {
+    lien: &'a SecondLien,
+    rounding: Rounding,
+    balance: Decimal,
+    rate: f64,
+    payment: Decimal,
+}
+
+impl<'a> SecondLienLoan<'a> {
+    pub(crate) fn new(lien: &'a SecondLien, amount: f64, rounding: Rounding) -> Self {
+        Self {
+            lien,
+            rounding,
+            balance: rounding.cents(decimal(amount)),
+            rate: f64::NAN,
+            payment: Decimal::ZERO,
+        }
+    }
+
+    pub(crate) fn balance(&self) -> f64 {
+        to_f64(self.balance)
+    }
+
+    pub(crate) fn is_paid_off(&self) -> bool {
+        self.balance.is_zero()
+    }
+
+    /// Makes `month`'s payment. The payment re-amortizes over the remaining term when the
+    /// draw period ends and whenever the rate changes; the last month of the term pays
+    /// off whatever is left, as a balloon if that is more than a regular payment.
+    pub(crate) fn pay(&mut self, month: u32) -> LienPayment {
+        if self.balance.is_zero() {
+            return LienPayment::default();
+        }
+        let term_months = self.lien.term.months();
+        let draw_months = self.lien.interest_only_months();
+        let rate = self.lien.rate_at(month);
+        if month > draw_months && (rate != self.rate || month == draw_months + 1) {
+            let remaining = term_months.saturating_sub(month - 1).max(1);
+            self.payment = self
+                .rounding
+                .cents(decimal(amortizing_payment(self.balance(), rate / 12.0, remaining as f64)));
+        }
+        self.rate = rate;
+
+        let interest = self.rounding.cents(self.balance * decimal(rate) / Decimal::from(12));
+        let (scheduled, mut principal) = if month <= draw_months {
+            (interest, Decimal::ZERO)
+        } else {
+            (self.payment, (self.payment - interest).max(Decimal::ZERO).min(self.balance))
+        };
+
+        // Cent rounding leaves a small residual that the final payment absorbs
+        let mut balloon = Decimal::ZERO;
+        if month >= term_months {
+            let unpaid = self.balance - principal;
+            if unpaid > scheduled {
+                balloon = unpaid;
+            } else {
+                principal += unpaid;
+            }
+        }
+        self.balance -= principal + balloon;
+        LienPayment {
+            interest,
+            principal,
+            balloon,
+            rate,
+        }
+    }
+}
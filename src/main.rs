@@ -9,29 +9,66 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
     Frame, Terminal,
 };
+use homebuyer::{
+    analyze_refinance, analyze_sale, analyze_sensitivity, compare_frequencies, compare_prepayments,
+    compare_rent, goal_seek, run_monte_carlo, simulate, solve_affordability, Affordability,
+    AffordabilityInputs, Arm, Assessment, ClosingCostItem, ClosingCosts, Cost, Escalation,
+    FilingStatus, FrequencyOutcome, GoalInput, GoalOutput, GoalSeek, GoalSeekError, IndexPath,
+    LoanProgram, LoanTerm, MonteCarlo, MonteCarloAssumptions, MortgageRow, PaymentFrequency,
+    Percentiles, PmiCancellation, Prepayment, PrepaymentComparison, PrepaymentOutcome,
+    PresentValues, Refinance, RefinanceAnalysis, RentComparison, RentScenario, RentalUnit,
+    Rounding, SaleAnalysis, SaleAssumptions, Scenario, Schedule, SecondLien, SecondLienKind,
+    SensitivityAnalysis, SensitivityMetric, TaxProfile,
+};
 use std::{
     fs::File,
-    io::{self, Write},
+    io::{self, BufReader},
 };
 
 #[derive(Debug, Clone, PartialEq)]
 enum Screen {
     HouseValue,
     DownPayment,
+    LoanProgram,
     HOAFee,
     InterestRate,
+    RateType,
+    ClosingCosts,
     PropertyTax,
+    Assessment,
     Insurance,
     Maintenance,
-    PMI,
+    RentalIncome,
+    Pmi,
     HouseAppreciation,
+    Inflation,
+    InvestmentReturn,
+    IncomeTax,
     LoanTerm,
+    LoanStructure,
+    SecondLien,
+    PaymentFrequency,
+    Prepayments,
+    Refinance,
     ExtraPrincipal,
     Spreadsheet,
     Summary,
+    RentInputs,
+    RentComparison,
+    SaleInputs,
+    SaleResult,
+    MonteCarloInputs,
+    MonteCarloResult,
+    SensitivityInputs,
+    Tornado,
+    AffordabilityInputs,
+    AffordabilityResult,
+    GoalSeekInputs,
+    GoalSeekResult,
+    Equity,
 }
 
 #[derive(Debug, Clone)]
@@ -40,11 +77,16 @@ struct MortgageInputs {
     down_payment_percent: String,
     down_payment_amount: String,
     use_percent: bool,
+    loan_program: LoanProgram,
     hoa_fee: String,
     interest_rate: String,
     property_tax_percent: String,
     property_tax_amount: String,
     use_property_tax_percent: bool,
+    assessment_cap: String,
+    reassessment_years: String,
+    homestead_exemption: String,
+    senior_freeze_year: String,
     insurance_percent: String,
     insurance_amount: String,
     use_insurance_percent: bool,
@@ -54,56 +96,133 @@ struct MortgageInputs {
     pmi_percent: String,
     pmi_amount: String,
     use_pmi_percent: bool,
+    pmi_borrower_request: bool,
+    pmi_new_appraisal: bool,
     house_appreciation_rate: String,
+    inflation_rate: String,
+    hoa_escalation: String,
+    property_tax_escalation: String,
+    insurance_escalation: String,
+    maintenance_escalation: String,
+    pmi_escalation: String,
+    discount_rate: String,
+    investment_return: String,
+    investment_tax_rate: String,
+    filing_status: Option<FilingStatus>,
+    marginal_tax_rate: String,
+    standard_deduction: String,
+    salt_cap: String,
+    mortgage_debt_limit: String,
+    state_income_tax: String,
+    other_itemized: String,
     loan_term_years: String,
+    loan_term_months: String,
+    use_loan_term_years: bool,
+    interest_only_years: String,
+    balloon_years: String,
+    second_lien: Option<SecondLienType>,
+    second_lien_percent: String,
+    second_lien_rate: String,
+    second_lien_years: String,
+    heloc_index: String,
+    heloc_margin: String,
+    heloc_years: String,
+    heloc_draw_years: String,
+    payment_frequency: PaymentFrequency,
     extra_principal_payment: String,
-}
-
-#[derive(Debug, Clone)]
-struct MortgageRow {
-    month: u32,
-    interest: f64,
-    principal: f64,
-    extra_principal: f64,
-    repair_costs: f64,
-    hoa: f64,
-    taxes: f64,
-    insurance: f64,
-    pmi: f64,
-    actual_payment: f64,
-    cost_of_capital: f64,
-    waste_cost: f64,
-    cost: f64,
-    debt: f64,
-    interest_rate: f64,
-    house_cost: f64,
-    equity: f64,
-}
-
-#[derive(Debug, Clone)]
-struct MortgageSummary {
-    total_interest_paid: f64,
-    total_principal_paid: f64,
-    total_taxes_paid: f64,
-    total_insurance_paid: f64,
-    total_maintenance_paid: f64,
-    total_pmi_paid: f64,
-    total_hoa_paid: f64,
-    total_payments: f64,
-    total_cost_of_capital: f64,
-    total_waste_cost: f64,
-    final_house_value: f64,
-    final_equity: f64,
-    months_to_payoff: u32,
-    effective_interest_rate: f64,
+    rounding: Rounding,
+    lump_sum_amount: String,
+    lump_sum_month: String,
+    recurring_prepayment: String,
+    recurring_first_month: String,
+    recurring_every_months: String,
+    prepayment_csv: String,
+    recast: bool,
+    rental_units: String,
+    unit_rent: String,
+    unit_vacancy: String,
+    unit_rent_growth: String,
+    unit_management_fee: String,
+    unit_extra_repairs: String,
+    refinance_month: String,
+    refinance_rate: String,
+    refinance_years: String,
+    refinance_closing_costs: String,
+    refinance_cash_out: String,
+    use_arm: bool,
+    arm_fixed_years: String,
+    arm_adjustment_months: String,
+    arm_index_rate: String,
+    arm_index_csv: String,
+    arm_margin: String,
+    arm_initial_cap: String,
+    arm_periodic_cap: String,
+    arm_lifetime_cap: String,
+    arm_floor: String,
+    closing_lender_fees: String,
+    closing_appraisal: String,
+    closing_title: String,
+    closing_recording: String,
+    closing_other: String,
+    discount_points: String,
+    lender_credit_points: String,
+    rate_change_per_point: String,
+    seller_concessions: String,
+    monthly_rent: String,
+    rent_growth: String,
+    renters_insurance: String,
+    rent_deposit: String,
+    sale_holding_years: String,
+    sale_commission: String,
+    sale_transfer_tax: String,
+    sale_other_costs: String,
+    monte_carlo_paths: String,
+    monte_carlo_seed: String,
+    monte_carlo_appreciation_mean: String,
+    monte_carlo_appreciation_volatility: String,
+    monte_carlo_return_mean: String,
+    monte_carlo_return_volatility: String,
+    monte_carlo_index_drift: String,
+    monte_carlo_index_volatility: String,
+    monte_carlo_appreciation_return_correlation: String,
+    monte_carlo_appreciation_index_correlation: String,
+    monte_carlo_return_index_correlation: String,
+    sensitivity_change: String,
+    gross_annual_income: String,
+    monthly_debts: String,
+    front_end_dti: String,
+    back_end_dti: String,
+    available_cash: String,
+    goal_output: usize,
+    goal_input: GoalInput,
+    goal_target: String,
+    goal_equity_year: String,
 }
 
 struct App {
     screen: Screen,
     inputs: MortgageInputs,
-    spreadsheet_data: Vec<MortgageRow>,
+    schedule: Option<Schedule>,
     table_state: TableState,
-    summary: Option<MortgageSummary>,
+    form_field: usize,
+    rent_comparison: Option<RentComparison>,
+    rent_table_state: TableState,
+    sale: Option<SaleAnalysis>,
+    monte_carlo: Option<MonteCarlo>,
+    sensitivity: Option<SensitivityAnalysis>,
+    sensitivity_metric: SensitivityMetric,
+    affordability: Option<Affordability>,
+    goal_seek: Option<std::result::Result<GoalSeek, GoalSeekError>>,
+    equity_table_state: TableState,
+    prepayment_comparison: Option<PrepaymentComparison>,
+    refinance: Option<RefinanceAnalysis>,
+    frequency_comparison: Option<Vec<FrequencyOutcome>>,
+    /// Show the spreadsheet and summary deflated to today's dollars.
+    real_dollars: bool,
+    real_schedule: Option<Schedule>,
+    present_values: Option<PresentValues>,
+    /// Lines scrolled past at the top of the summary.
+    summary_scroll: u16,
 }
 
 impl Default for App {
@@ -115,11 +234,16 @@ impl Default for App {
                 down_payment_percent: "20".to_string(),
                 down_payment_amount: String::new(),
                 use_percent: true,
+                loan_program: LoanProgram::Conventional,
                 hoa_fee: "0".to_string(),
                 interest_rate: "6.5".to_string(),
                 property_tax_percent: "2".to_string(),
                 property_tax_amount: String::new(),
                 use_property_tax_percent: true,
+                assessment_cap: String::new(),
+                reassessment_years: String::new(),
+                homestead_exemption: "0".to_string(),
+                senior_freeze_year: String::new(),
                 insurance_percent: "0.35".to_string(),
                 insurance_amount: String::new(),
                 use_insurance_percent: true,
@@ -129,281 +253,853 @@ impl Default for App {
                 pmi_percent: "0.5".to_string(),
                 pmi_amount: String::new(),
                 use_pmi_percent: true,
+                pmi_borrower_request: true,
+                pmi_new_appraisal: false,
                 house_appreciation_rate: "3".to_string(),
+                inflation_rate: "3".to_string(),
+                hoa_escalation: String::new(),
+                property_tax_escalation: String::new(),
+                insurance_escalation: String::new(),
+                maintenance_escalation: String::new(),
+                pmi_escalation: String::new(),
+                discount_rate: String::new(),
+                investment_return: "7".to_string(),
+                investment_tax_rate: "0".to_string(),
+                filing_status: None,
+                marginal_tax_rate: "22".to_string(),
+                standard_deduction: String::new(),
+                salt_cap: String::new(),
+                mortgage_debt_limit: String::new(),
+                state_income_tax: "0".to_string(),
+                other_itemized: "0".to_string(),
                 loan_term_years: "30".to_string(),
+                loan_term_months: String::new(),
+                use_loan_term_years: true,
+                interest_only_years: "0".to_string(),
+                balloon_years: String::new(),
+                second_lien: None,
+                second_lien_percent: "10".to_string(),
+                second_lien_rate: "8".to_string(),
+                second_lien_years: "15".to_string(),
+                heloc_index: "7.5".to_string(),
+                heloc_margin: "0.5".to_string(),
+                heloc_years: "30".to_string(),
+                heloc_draw_years: "10".to_string(),
+                payment_frequency: PaymentFrequency::Monthly,
                 extra_principal_payment: "0".to_string(),
+                rounding: Rounding::HalfUp,
+                lump_sum_amount: "0".to_string(),
+                lump_sum_month: String::new(),
+                recurring_prepayment: "0".to_string(),
+                recurring_first_month: String::new(),
+                recurring_every_months: "12".to_string(),
+                rental_units: "0".to_string(),
+                unit_rent: "0".to_string(),
+                unit_vacancy: "5".to_string(),
+                unit_rent_growth: "3".to_string(),
+                unit_management_fee: "0".to_string(),
+                unit_extra_repairs: "0".to_string(),
+                refinance_month: String::new(),
+                refinance_rate: "5.5".to_string(),
+                refinance_years: "30".to_string(),
+                refinance_closing_costs: "0".to_string(),
+                refinance_cash_out: "0".to_string(),
+                prepayment_csv: String::new(),
+                recast: false,
+                use_arm: false,
+                arm_fixed_years: "5".to_string(),
+                arm_adjustment_months: "12".to_string(),
+                arm_index_rate: "4.5".to_string(),
+                arm_index_csv: String::new(),
+                arm_margin: "2.75".to_string(),
+                arm_initial_cap: "2".to_string(),
+                arm_periodic_cap: "2".to_string(),
+                arm_lifetime_cap: "5".to_string(),
+                arm_floor: "2.75".to_string(),
+                closing_lender_fees: "0".to_string(),
+                closing_appraisal: "0".to_string(),
+                closing_title: "0".to_string(),
+                closing_recording: "0".to_string(),
+                closing_other: "0".to_string(),
+                discount_points: "0".to_string(),
+                lender_credit_points: "0".to_string(),
+                rate_change_per_point: "0.25".to_string(),
+                seller_concessions: "0".to_string(),
+                monthly_rent: String::new(),
+                rent_growth: "3".to_string(),
+                renters_insurance: "15".to_string(),
+                rent_deposit: "0".to_string(),
+                sale_holding_years: "7".to_string(),
+                sale_commission: "6".to_string(),
+                sale_transfer_tax: "1".to_string(),
+                sale_other_costs: "0".to_string(),
+                monte_carlo_paths: "1000".to_string(),
+                monte_carlo_seed: "1".to_string(),
+                monte_carlo_appreciation_mean: String::new(),
+                monte_carlo_appreciation_volatility: "5".to_string(),
+                monte_carlo_return_mean: String::new(),
+                monte_carlo_return_volatility: "15".to_string(),
+                monte_carlo_index_drift: "0".to_string(),
+                monte_carlo_index_volatility: "1".to_string(),
+                monte_carlo_appreciation_return_correlation: "0.2".to_string(),
+                monte_carlo_appreciation_index_correlation: "-0.3".to_string(),
+                monte_carlo_return_index_correlation: "0".to_string(),
+                sensitivity_change: "10".to_string(),
+                gross_annual_income: String::new(),
+                monthly_debts: "0".to_string(),
+                front_end_dti: "28".to_string(),
+                back_end_dti: "36".to_string(),
+                available_cash: String::new(),
+                goal_output: 0,
+                goal_input: GoalInput::DownPayment,
+                goal_target: String::new(),
+                goal_equity_year: "10".to_string(),
             },
-            spreadsheet_data: Vec::new(),
+            schedule: None,
             table_state: TableState::default(),
-            summary: None,
+            form_field: 0,
+            rent_comparison: None,
+            rent_table_state: TableState::default(),
+            sale: None,
+            monte_carlo: None,
+            sensitivity: None,
+            sensitivity_metric: SensitivityMetric::TotalWasteCost,
+            affordability: None,
+            goal_seek: None,
+            equity_table_state: TableState::default(),
+            prepayment_comparison: None,
+            refinance: None,
+            frequency_comparison: None,
+            real_dollars: false,
+            real_schedule: None,
+            present_values: None,
+            summary_scroll: 0,
         }
     }
 }
 
-impl App {
-    fn calculate_mortgage(&mut self) -> Result<()> {
-        let house_value: f64 = self.inputs.house_value.parse()?;
-        let hoa_monthly: f64 = self.inputs.hoa_fee.parse()?;
-        let annual_interest_rate: f64 = self.inputs.interest_rate.parse::<f64>()? / 100.0;
-        let monthly_interest_rate = annual_interest_rate / 12.0;
-        
-        let down_payment = if self.inputs.use_percent {
-            let percent: f64 = self.inputs.down_payment_percent.parse()?;
-            house_value * (percent / 100.0)
-        } else {
-            self.inputs.down_payment_amount.parse()?
+impl MortgageInputs {
+    fn to_scenario(&self) -> Result<Scenario> {
+        let scenario = Scenario {
+            house_value: self.house_value.parse()?,
+            down_payment: parse_cost(
+                self.use_percent,
+                &self.down_payment_percent,
+                &self.down_payment_amount,
+            )?,
+            loan_program: self.loan_program,
+            hoa_monthly: self.hoa_fee.parse()?,
+            rental_units: self.to_rental_units()?,
+            interest_rate: self.interest_rate.parse::<f64>()? / 100.0,
+            arm: if self.use_arm { Some(self.to_arm()?) } else { None },
+            second_lien: self.to_second_lien()?,
+            closing_costs: self.to_closing_costs()?,
+            property_tax: parse_cost(
+                self.use_property_tax_percent,
+                &self.property_tax_percent,
+                &self.property_tax_amount,
+            )?,
+            assessment: Assessment {
+                annual_cap: parse_optional_percent(&self.assessment_cap)?,
+                reassessment_years: parse_optional(&self.reassessment_years)?,
+                homestead_exemption: self.homestead_exemption.parse()?,
+                freeze_year: parse_optional(&self.senior_freeze_year)?,
+            },
+            insurance: parse_cost(
+                self.use_insurance_percent,
+                &self.insurance_percent,
+                &self.insurance_amount,
+            )?,
+            maintenance: parse_cost(
+                self.use_maintenance_percent,
+                &self.maintenance_percent,
+                &self.maintenance_amount,
+            )?,
+            pmi: parse_cost(self.use_pmi_percent, &self.pmi_percent, &self.pmi_amount)?,
+            pmi_cancellation: PmiCancellation {
+                borrower_request: self.pmi_borrower_request,
+                new_appraisal: self.pmi_new_appraisal,
+            },
+            appreciation_rate: self.house_appreciation_rate.parse::<f64>()? / 100.0,
+            inflation_rate: self.inflation_rate.parse::<f64>()? / 100.0,
+            escalation: Escalation {
+                hoa: parse_optional_percent(&self.hoa_escalation)?,
+                property_tax: parse_optional_percent(&self.property_tax_escalation)?,
+                insurance: parse_optional_percent(&self.insurance_escalation)?,
+                maintenance: parse_optional_percent(&self.maintenance_escalation)?,
+                pmi: parse_optional_percent(&self.pmi_escalation)?,
+            },
+            investment_return: self.investment_return.parse::<f64>()? / 100.0,
+            investment_tax_rate: self.investment_tax_rate.parse::<f64>()? / 100.0,
+            tax: self.to_tax_profile()?,
+            loan_term: if self.use_loan_term_years {
+                LoanTerm::Years(self.loan_term_years.parse()?)
+            } else {
+                LoanTerm::Months(self.loan_term_months.parse()?)
+            },
+            interest_only_months: years_to_months(self.interest_only_years.parse()?),
+            balloon_month: parse_optional(&self.balloon_years)?.map(years_to_months),
+            payment_frequency: self.payment_frequency,
+            extra_principal: self.extra_principal_payment.parse()?,
+            prepayments: self.to_prepayments()?,
+            recast: self.recast,
+            refinance: self.to_refinance()?,
+            rounding: self.rounding,
         };
-        
-        let loan_amount = house_value - down_payment;
-        let down_payment_percent = down_payment / house_value;
-        
-        // Calculate monthly payment using standard mortgage formula
-        let loan_term_years: f64 = self.inputs.loan_term_years.parse()?;
-        let num_payments = (loan_term_years * 12.0) as i32;
-        let monthly_payment = if monthly_interest_rate > 0.0 {
-            loan_amount * (monthly_interest_rate * (1.0 + monthly_interest_rate).powf(num_payments as f64)) 
-                / ((1.0 + monthly_interest_rate).powf(num_payments as f64) - 1.0)
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    fn to_arm(&self) -> Result<Arm> {
+        let index = if self.arm_index_csv.is_empty() {
+            IndexPath::Constant(self.arm_index_rate.parse::<f64>()? / 100.0)
         } else {
-            loan_amount / num_payments as f64
+            IndexPath::from_csv(BufReader::new(File::open(&self.arm_index_csv)?))?
         };
-        
-        let extra_principal: f64 = self.inputs.extra_principal_payment.parse()?;
-        
-        // Property tax calculation
-        let (annual_tax_rate, annual_tax_amount) = if self.inputs.use_property_tax_percent {
-            let rate = self.inputs.property_tax_percent.parse::<f64>()? / 100.0;
-            (rate, 0.0)
-        } else {
-            let amount = self.inputs.property_tax_amount.parse::<f64>()?;
-            (0.0, amount)
+        let arm = Arm {
+            initial_period_months: (self.arm_fixed_years.parse::<f64>()? * 12.0).round() as u32,
+            adjustment_period_months: self.arm_adjustment_months.parse()?,
+            index,
+            margin: self.arm_margin.parse::<f64>()? / 100.0,
+            initial_cap: self.arm_initial_cap.parse::<f64>()? / 100.0,
+            periodic_cap: self.arm_periodic_cap.parse::<f64>()? / 100.0,
+            lifetime_cap: self.arm_lifetime_cap.parse::<f64>()? / 100.0,
+            floor: self.arm_floor.parse::<f64>()? / 100.0,
         };
-        
-        // Insurance calculation
-        let (annual_insurance_rate, annual_insurance_amount) = if self.inputs.use_insurance_percent {
-            let rate = self.inputs.insurance_percent.parse::<f64>()? / 100.0;
-            (rate, 0.0)
-        } else {
-            let amount = self.inputs.insurance_amount.parse::<f64>()?;
-            (0.0, amount)
+        anyhow::ensure!(
+            arm.initial_cap >= 0.0 && arm.periodic_cap >= 0.0 && arm.lifetime_cap >= 0.0,
+            "ARM caps cannot be negative"
+        );
+        anyhow::ensure!(arm.floor >= 0.0, "ARM floor cannot be negative");
+        Ok(arm)
+    }
+
+    fn to_prepayments(&self) -> Result<Vec<Prepayment>> {
+        let mut prepayments = Vec::new();
+        let lump_sum: f64 = self.lump_sum_amount.parse()?;
+        if lump_sum > 0.0 {
+            prepayments.push(Prepayment::once(self.lump_sum_month.parse()?, lump_sum));
+        }
+        let recurring: f64 = self.recurring_prepayment.parse()?;
+        if recurring > 0.0 {
+            prepayments.push(Prepayment::recurring(
+                self.recurring_first_month.parse()?,
+                recurring,
+                self.recurring_every_months.parse()?,
+            ));
+        }
+        if !self.prepayment_csv.is_empty() {
+            prepayments.extend(Prepayment::from_csv(BufReader::new(File::open(&self.prepayment_csv)?))?);
+        }
+        Ok(prepayments)
+    }
+
+    fn rental_values(&mut self) -> [&mut String; 6] {
+        [
+            &mut self.rental_units,
+            &mut self.unit_rent,
+            &mut self.unit_vacancy,
+            &mut self.unit_rent_growth,
+            &mut self.unit_management_fee,
+            &mut self.unit_extra_repairs,
+        ]
+    }
+
+    /// The rental screen describes identical units; each becomes its own `RentalUnit`.
+    fn to_rental_units(&self) -> Result<Vec<RentalUnit>> {
+        let unit = RentalUnit {
+            monthly_rent: self.unit_rent.parse()?,
+            vacancy_rate: self.unit_vacancy.parse::<f64>()? / 100.0,
+            rent_growth: self.unit_rent_growth.parse::<f64>()? / 100.0,
+            management_fee: self.unit_management_fee.parse::<f64>()? / 100.0,
+            extra_repairs: self.unit_extra_repairs.parse()?,
         };
-        
-        // Maintenance calculation
-        let (annual_maintenance_rate, annual_maintenance_amount) = if self.inputs.use_maintenance_percent {
-            let rate = self.inputs.maintenance_percent.parse::<f64>()? / 100.0;
-            (rate, 0.0)
-        } else {
-            let amount = self.inputs.maintenance_amount.parse::<f64>()?;
-            (0.0, amount)
+        Ok(vec![unit; self.rental_units.parse()?])
+    }
+
+    fn refinance_values(&mut self) -> [&mut String; 5] {
+        [
+            &mut self.refinance_month,
+            &mut self.refinance_rate,
+            &mut self.refinance_years,
+            &mut self.refinance_closing_costs,
+            &mut self.refinance_cash_out,
+        ]
+    }
+
+    fn to_refinance(&self) -> Result<Option<Refinance>> {
+        let Some(month) = parse_optional(&self.refinance_month)? else {
+            return Ok(None);
         };
-        
-        // PMI calculation (only if down payment < 20%)
-        let (pmi_rate, monthly_pmi_amount) = if down_payment_percent < 0.20 {
-            if self.inputs.use_pmi_percent {
-                let rate = self.inputs.pmi_percent.parse::<f64>()? / 100.0;
-                (rate, 0.0)
-            } else {
-                let amount = self.inputs.pmi_amount.parse::<f64>()?;
-                (0.0, amount)
-            }
-        } else {
-            (0.0, 0.0)
+        let term = LoanTerm::Years(self.refinance_years.parse()?);
+        anyhow::ensure!(month > 0, "refinance month must be at least 1");
+        anyhow::ensure!(term.months() > 0, "refinance term must be at least one month");
+        Ok(Some(Refinance {
+            month,
+            rate: self.refinance_rate.parse::<f64>()? / 100.0,
+            term,
+            closing_costs: self.refinance_closing_costs.parse()?,
+            cash_out: self.refinance_cash_out.parse()?,
+        }))
+    }
+
+    fn prepayment_values(&mut self) -> [&mut String; 6] {
+        [
+            &mut self.lump_sum_amount,
+            &mut self.lump_sum_month,
+            &mut self.recurring_prepayment,
+            &mut self.recurring_first_month,
+            &mut self.recurring_every_months,
+            &mut self.prepayment_csv,
+        ]
+    }
+
+    fn to_tax_profile(&self) -> Result<Option<TaxProfile>> {
+        let Some(filing_status) = self.filing_status else {
+            return Ok(None);
         };
-        
-        // House appreciation rate
-        let annual_appreciation_rate = self.inputs.house_appreciation_rate.parse::<f64>()? / 100.0;
-        let monthly_appreciation_rate = annual_appreciation_rate / 12.0;
-        
-        self.spreadsheet_data.clear();
-        let mut remaining_balance = loan_amount;
-        let mut current_house_value = house_value;
-        
-        // Summary tracking variables
-        let mut total_interest = 0.0;
-        let mut total_principal = 0.0;
-        let mut total_taxes = 0.0;
-        let mut total_insurance = 0.0;
-        let mut total_maintenance = 0.0;
-        let mut total_pmi = 0.0;
-        let mut total_hoa = 0.0;
-        let mut total_payments = 0.0;
-        let mut total_cost_of_capital = 0.0;
-        let mut total_waste_cost = 0.0;
-        let mut actual_months = 0;
-        
-        for month in 1..=360 {
-            if remaining_balance <= 0.0 {
-                break;
-            }
-            
-            let interest_payment = remaining_balance * monthly_interest_rate;
-            let mut principal_payment = monthly_payment - interest_payment;
-            
-            // Ensure we don't overpay
-            if principal_payment + extra_principal > remaining_balance {
-                principal_payment = remaining_balance;
+        Ok(Some(TaxProfile {
+            filing_status,
+            marginal_rate: self.marginal_tax_rate.parse::<f64>()? / 100.0,
+            standard_deduction: self.standard_deduction.parse()?,
+            salt_cap: self.salt_cap.parse()?,
+            mortgage_debt_limit: self.mortgage_debt_limit.parse()?,
+            state_income_tax: self.state_income_tax.parse()?,
+            other_itemized: self.other_itemized.parse()?,
+        }))
+    }
+
+    /// Switches filing status and resets the status-dependent limits to their defaults.
+    fn set_filing_status(&mut self, filing_status: Option<FilingStatus>) {
+        self.filing_status = filing_status;
+        if let Some(filing_status) = filing_status {
+            let defaults = TaxProfile::new(filing_status, 0.0);
+            self.standard_deduction = defaults.standard_deduction.to_string();
+            self.salt_cap = defaults.salt_cap.to_string();
+            self.mortgage_debt_limit = defaults.mortgage_debt_limit.to_string();
+        }
+    }
+
+    fn inflation_values(&mut self) -> [&mut String; 7] {
+        [
+            &mut self.inflation_rate,
+            &mut self.hoa_escalation,
+            &mut self.property_tax_escalation,
+            &mut self.insurance_escalation,
+            &mut self.maintenance_escalation,
+            &mut self.pmi_escalation,
+            &mut self.discount_rate,
+        ]
+    }
+
+    fn assessment_values(&mut self) -> [&mut String; 4] {
+        [
+            &mut self.assessment_cap,
+            &mut self.reassessment_years,
+            &mut self.homestead_exemption,
+            &mut self.senior_freeze_year,
+        ]
+    }
+
+    fn tax_values(&mut self) -> [&mut String; 6] {
+        [
+            &mut self.marginal_tax_rate,
+            &mut self.standard_deduction,
+            &mut self.salt_cap,
+            &mut self.mortgage_debt_limit,
+            &mut self.state_income_tax,
+            &mut self.other_itemized,
+        ]
+    }
+
+    fn to_rent_scenario(&self) -> Result<RentScenario> {
+        Ok(RentScenario {
+            monthly_rent: self.monthly_rent.parse()?,
+            rent_growth: self.rent_growth.parse::<f64>()? / 100.0,
+            renters_insurance_monthly: self.renters_insurance.parse()?,
+            deposit: self.rent_deposit.parse()?,
+        })
+    }
+
+    fn to_sale_assumptions(&self) -> Result<SaleAssumptions> {
+        Ok(SaleAssumptions {
+            holding_months: (self.sale_holding_years.parse::<f64>()? * 12.0).round() as u32,
+            commission_rate: self.sale_commission.parse::<f64>()? / 100.0,
+            transfer_tax_rate: self.sale_transfer_tax.parse::<f64>()? / 100.0,
+            other_costs: self.sale_other_costs.parse()?,
+        })
+    }
+
+    /// Blank means fall back to the scenario's own appreciation and investment return.
+    fn to_monte_carlo_assumptions(&self, scenario: &Scenario) -> Result<MonteCarloAssumptions> {
+        let mut assumptions = MonteCarloAssumptions::for_scenario(scenario);
+        assumptions.paths = self.monte_carlo_paths.parse()?;
+        assumptions.seed = self.monte_carlo_seed.parse()?;
+        if let Some(mean) = parse_optional_percent(&self.monte_carlo_appreciation_mean)? {
+            assumptions.appreciation.mean = mean;
+        }
+        assumptions.appreciation.volatility = self.monte_carlo_appreciation_volatility.parse::<f64>()? / 100.0;
+        if let Some(mean) = parse_optional_percent(&self.monte_carlo_return_mean)? {
+            assumptions.investment_return.mean = mean;
+        }
+        assumptions.investment_return.volatility = self.monte_carlo_return_volatility.parse::<f64>()? / 100.0;
+        assumptions.index_change.mean = self.monte_carlo_index_drift.parse::<f64>()? / 100.0;
+        assumptions.index_change.volatility = self.monte_carlo_index_volatility.parse::<f64>()? / 100.0;
+        assumptions.appreciation_return_correlation = self.monte_carlo_appreciation_return_correlation.parse()?;
+        assumptions.appreciation_index_correlation = self.monte_carlo_appreciation_index_correlation.parse()?;
+        assumptions.return_index_correlation = self.monte_carlo_return_index_correlation.parse()?;
+        // Net worth is measured against renting once the rent comparison has been filled in
+        assumptions.rent = self.to_rent_scenario().ok();
+        Ok(assumptions)
+    }
+
+    fn monte_carlo_values(&mut self) -> [&mut String; 11] {
+        [
+            &mut self.monte_carlo_paths,
+            &mut self.monte_carlo_seed,
+            &mut self.monte_carlo_appreciation_mean,
+            &mut self.monte_carlo_appreciation_volatility,
+            &mut self.monte_carlo_return_mean,
+            &mut self.monte_carlo_return_volatility,
+            &mut self.monte_carlo_index_drift,
+            &mut self.monte_carlo_index_volatility,
+            &mut self.monte_carlo_appreciation_return_correlation,
+            &mut self.monte_carlo_appreciation_index_correlation,
+            &mut self.monte_carlo_return_index_correlation,
+        ]
+    }
+
+    fn to_affordability_inputs(&self) -> Result<AffordabilityInputs> {
+        Ok(AffordabilityInputs {
+            gross_annual_income: self.gross_annual_income.parse()?,
+            monthly_debts: self.monthly_debts.parse()?,
+            front_end_dti: self.front_end_dti.parse::<f64>()? / 100.0,
+            back_end_dti: self.back_end_dti.parse::<f64>()? / 100.0,
+            available_cash: self.available_cash.parse()?,
+        })
+    }
+
+    fn to_goal_output(&self) -> Result<GoalOutput> {
+        Ok(match self.goal_output {
+            0 => GoalOutput::FirstPayment,
+            1 => GoalOutput::TotalWasteCost,
+            2 => GoalOutput::PayoffMonth,
+            _ => GoalOutput::EquityAtYear(self.goal_equity_year.parse()?),
+        })
+    }
+
+    /// Writes a goal-seek result back into the matching input.
+    fn apply_goal_input(&mut self, input: GoalInput, value: f64) {
+        match input {
+            GoalInput::DownPayment => {
+                self.use_percent = false;
+                self.down_payment_amount = format!("{:.2}", value);
             }
-            
-            // Calculate monthly costs
-            current_house_value *= 1.0 + monthly_appreciation_rate;
-            
-            let monthly_taxes = if annual_tax_rate > 0.0 {
-                current_house_value * annual_tax_rate / 12.0
-            } else {
-                annual_tax_amount / 12.0
-            };
-            
-            let monthly_insurance = if annual_insurance_rate > 0.0 {
-                current_house_value * annual_insurance_rate / 12.0
-            } else {
-                annual_insurance_amount / 12.0
-            };
-            
-            let monthly_pmi = if down_payment_percent < 0.20 && remaining_balance > 0.0 {
-                if pmi_rate > 0.0 {
-                    remaining_balance * pmi_rate / 12.0
-                } else {
-                    monthly_pmi_amount
-                }
-            } else {
-                0.0
-            };
-            
-            let monthly_repairs = if annual_maintenance_rate > 0.0 {
-                current_house_value * annual_maintenance_rate / 12.0
-            } else {
-                annual_maintenance_amount / 12.0
-            };
-            
-            let total_payment = interest_payment + principal_payment + extra_principal + 
-                               monthly_repairs + hoa_monthly + monthly_taxes + monthly_insurance + monthly_pmi;
-            
-            // Cost of capital (opportunity cost)
-            let equity = current_house_value - remaining_balance;
-            let cost_of_capital = equity * annual_interest_rate / 12.0;
-            
-            // Waste cost = all non-principal payments
-            let waste_cost = interest_payment + monthly_repairs + hoa_monthly + monthly_taxes + 
-                            monthly_insurance + monthly_pmi + cost_of_capital;
-            
-            // Total cost
-            let total_cost = total_payment - principal_payment - extra_principal + cost_of_capital;
-            
-            remaining_balance -= principal_payment + extra_principal;
-            
-            // Update summary totals
-            total_interest += interest_payment;
-            total_principal += principal_payment + extra_principal;
-            total_taxes += monthly_taxes;
-            total_insurance += monthly_insurance;
-            total_maintenance += monthly_repairs;
-            total_pmi += monthly_pmi;
-            total_hoa += hoa_monthly;
-            total_payments += total_payment;
-            total_cost_of_capital += cost_of_capital;
-            total_waste_cost += waste_cost;
-            actual_months = month;
-            
-            self.spreadsheet_data.push(MortgageRow {
-                month,
-                interest: interest_payment,
-                principal: principal_payment,
-                extra_principal,
-                repair_costs: monthly_repairs,
-                hoa: hoa_monthly,
-                taxes: monthly_taxes,
-                insurance: monthly_insurance,
-                pmi: monthly_pmi,
-                actual_payment: total_payment,
-                cost_of_capital,
-                waste_cost,
-                cost: total_cost,
-                debt: remaining_balance,
-                interest_rate: annual_interest_rate,
-                house_cost: current_house_value,
-                equity,
-            });
+            GoalInput::ExtraPrincipal => self.extra_principal_payment = format!("{:.2}", value),
+            GoalInput::InterestRate => self.interest_rate = format!("{:.3}", value * 100.0),
+            GoalInput::Price => self.house_value = format!("{:.2}", value),
         }
-        
-        // Calculate summary statistics
-        let final_house_value = current_house_value;
-        let final_equity = final_house_value;
-        let effective_interest_rate = if total_principal > 0.0 {
-            (total_interest / total_principal) * (12.0 / actual_months as f64)
-        } else {
-            0.0
+    }
+
+    fn goal_values(&mut self) -> [&mut String; 2] {
+        [&mut self.goal_target, &mut self.goal_equity_year]
+    }
+
+    fn affordability_values(&mut self) -> [&mut String; 5] {
+        [
+            &mut self.gross_annual_income,
+            &mut self.monthly_debts,
+            &mut self.front_end_dti,
+            &mut self.back_end_dti,
+            &mut self.available_cash,
+        ]
+    }
+
+    fn sale_values(&mut self) -> [&mut String; 4] {
+        [
+            &mut self.sale_holding_years,
+            &mut self.sale_commission,
+            &mut self.sale_transfer_tax,
+            &mut self.sale_other_costs,
+        ]
+    }
+
+    fn rent_values(&mut self) -> [&mut String; 4] {
+        [
+            &mut self.monthly_rent,
+            &mut self.rent_growth,
+            &mut self.renters_insurance,
+            &mut self.rent_deposit,
+        ]
+    }
+
+    fn loan_structure_values(&mut self) -> [&mut String; 2] {
+        [&mut self.interest_only_years, &mut self.balloon_years]
+    }
+
+    fn fixed_second_values(&mut self) -> [&mut String; 3] {
+        [&mut self.second_lien_percent, &mut self.second_lien_rate, &mut self.second_lien_years]
+    }
+
+    fn heloc_values(&mut self) -> [&mut String; 5] {
+        [
+            &mut self.second_lien_percent,
+            &mut self.heloc_index,
+            &mut self.heloc_margin,
+            &mut self.heloc_years,
+            &mut self.heloc_draw_years,
+        ]
+    }
+
+    fn to_second_lien(&self) -> Result<Option<SecondLien>> {
+        let Some(lien_type) = self.second_lien else {
+            return Ok(None);
         };
-        
-        self.summary = Some(MortgageSummary {
-            total_interest_paid: total_interest,
-            total_principal_paid: total_principal,
-            total_taxes_paid: total_taxes,
-            total_insurance_paid: total_insurance,
-            total_maintenance_paid: total_maintenance,
-            total_pmi_paid: total_pmi,
-            total_hoa_paid: total_hoa,
-            total_payments,
-            total_cost_of_capital,
-            total_waste_cost,
-            final_house_value,
-            final_equity,
-            months_to_payoff: actual_months,
-            effective_interest_rate,
-        });
-        
+        let amount = Cost::Rate(self.second_lien_percent.parse::<f64>()? / 100.0);
+        Ok(Some(match lien_type {
+            SecondLienType::Fixed => SecondLien {
+                amount,
+                term: LoanTerm::Years(self.second_lien_years.parse()?),
+                kind: SecondLienKind::Fixed {
+                    rate: self.second_lien_rate.parse::<f64>()? / 100.0,
+                },
+            },
+            SecondLienType::Heloc => SecondLien {
+                amount,
+                term: LoanTerm::Years(self.heloc_years.parse()?),
+                kind: SecondLienKind::Heloc {
+                    index: IndexPath::Constant(self.heloc_index.parse::<f64>()? / 100.0),
+                    margin: self.heloc_margin.parse::<f64>()? / 100.0,
+                    draw_months: years_to_months(self.heloc_draw_years.parse()?),
+                },
+            },
+        }))
+    }
+
+    fn investment_values(&mut self) -> [&mut String; 2] {
+        [&mut self.investment_return, &mut self.investment_tax_rate]
+    }
+
+    fn to_closing_costs(&self) -> Result<ClosingCosts> {
+        let mut items = Vec::new();
+        for (field, value) in CLOSING_FIELDS.iter().zip(self.closing_item_values()) {
+            items.push(ClosingCostItem {
+                name: field.label.to_string(),
+                amount: value.parse()?,
+            });
+        }
+        Ok(ClosingCosts {
+            items,
+            discount_points: self.discount_points.parse()?,
+            lender_credit_points: self.lender_credit_points.parse()?,
+            rate_change_per_point: self.rate_change_per_point.parse::<f64>()? / 100.0,
+            seller_concessions: self.seller_concessions.parse()?,
+        })
+    }
+
+    fn closing_item_values(&self) -> [&String; CLOSING_ITEM_COUNT] {
+        [
+            &self.closing_lender_fees,
+            &self.closing_appraisal,
+            &self.closing_title,
+            &self.closing_recording,
+            &self.closing_other,
+        ]
+    }
+
+    fn closing_values(&mut self) -> [&mut String; 9] {
+        [
+            &mut self.closing_lender_fees,
+            &mut self.closing_appraisal,
+            &mut self.closing_title,
+            &mut self.closing_recording,
+            &mut self.closing_other,
+            &mut self.discount_points,
+            &mut self.lender_credit_points,
+            &mut self.rate_change_per_point,
+            &mut self.seller_concessions,
+        ]
+    }
+
+    fn arm_values(&mut self) -> [&mut String; 9] {
+        [
+            &mut self.arm_fixed_years,
+            &mut self.arm_adjustment_months,
+            &mut self.arm_index_rate,
+            &mut self.arm_index_csv,
+            &mut self.arm_margin,
+            &mut self.arm_initial_cap,
+            &mut self.arm_periodic_cap,
+            &mut self.arm_lifetime_cap,
+            &mut self.arm_floor,
+        ]
+    }
+}
+
+const SALE_FIELDS: [FormField; 4] = [
+    FormField { label: "Holding Period", kind: FieldKind::Years },
+    FormField { label: "Realtor Commission", kind: FieldKind::Percent },
+    FormField { label: "Transfer Taxes", kind: FieldKind::Percent },
+    FormField { label: "Other Selling Costs", kind: FieldKind::Dollars },
+];
+
+const MONTE_CARLO_FIELDS: [FormField; 11] = [
+    FormField { label: "Simulated Paths", kind: FieldKind::Number },
+    FormField { label: "Random Seed", kind: FieldKind::Number },
+    FormField { label: "Mean Appreciation (blank for your rate)", kind: FieldKind::Percent },
+    FormField { label: "Appreciation Volatility", kind: FieldKind::Percent },
+    FormField { label: "Mean Investment Return (blank for your rate)", kind: FieldKind::Percent },
+    FormField { label: "Investment Return Volatility", kind: FieldKind::Percent },
+    FormField { label: "ARM Index Drift per Year", kind: FieldKind::Percent },
+    FormField { label: "ARM Index Volatility", kind: FieldKind::Percent },
+    FormField { label: "Correlation: Appreciation / Return", kind: FieldKind::Number },
+    FormField { label: "Correlation: Appreciation / Index", kind: FieldKind::Number },
+    FormField { label: "Correlation: Return / Index", kind: FieldKind::Number },
+];
+
+const AFFORDABILITY_FIELDS: [FormField; 5] = [
+    FormField { label: "Gross Annual Income", kind: FieldKind::Dollars },
+    FormField { label: "Other Monthly Debt Payments", kind: FieldKind::Dollars },
+    FormField { label: "Front-End DTI Limit (housing)", kind: FieldKind::Percent },
+    FormField { label: "Back-End DTI Limit (housing + debts)", kind: FieldKind::Percent },
+    FormField { label: "Cash Available to Close", kind: FieldKind::Dollars },
+];
+
+const GOAL_FIELDS: [FormField; 2] = [
+    FormField { label: "Target Value", kind: FieldKind::Number },
+    FormField { label: "Year (for equity)", kind: FieldKind::Years },
+];
+
+const GOAL_OUTPUT_LABELS: [&str; 4] = [
+    "Actual payment in month 1 ($)",
+    "Total after-tax waste cost ($)",
+    "Payoff month",
+    "Equity at a given year ($)",
+];
+
+const SENSITIVITY_FIELDS: [FormField; 1] = [
+    FormField { label: "Change in Each Input (up and down, % of its value)", kind: FieldKind::Percent },
+];
+
+const RENT_FIELDS: [FormField; 4] = [
+    FormField { label: "Monthly Rent", kind: FieldKind::Dollars },
+    FormField { label: "Annual Rent Growth", kind: FieldKind::Percent },
+    FormField { label: "Renters Insurance (monthly)", kind: FieldKind::Dollars },
+    FormField { label: "Security Deposit", kind: FieldKind::Dollars },
+];
+
+const INVESTMENT_FIELDS: [FormField; 2] = [
+    FormField { label: "Alternative Investment Return", kind: FieldKind::Percent },
+    FormField { label: "Tax Rate on Investment Return (0 for pre-tax)", kind: FieldKind::Percent },
+];
+
+const LOAN_STRUCTURE_FIELDS: [FormField; 2] = [
+    FormField { label: "Interest-Only Period", kind: FieldKind::Years },
+    FormField { label: "Balloon Due After (blank = fully amortizing)", kind: FieldKind::Years },
+];
+
+const FIXED_SECOND_FIELDS: [FormField; 3] = [
+    FormField { label: "Second Lien Amount (of price)", kind: FieldKind::Percent },
+    FormField { label: "Fixed Rate", kind: FieldKind::Percent },
+    FormField { label: "Term", kind: FieldKind::Years },
+];
+
+const HELOC_FIELDS: [FormField; 5] = [
+    FormField { label: "HELOC Amount Drawn at Purchase (of price)", kind: FieldKind::Percent },
+    FormField { label: "Index Rate (e.g. prime)", kind: FieldKind::Percent },
+    FormField { label: "Margin over Index", kind: FieldKind::Percent },
+    FormField { label: "Term", kind: FieldKind::Years },
+    FormField { label: "Draw Period (interest only)", kind: FieldKind::Years },
+];
+
+const RENTAL_FIELDS: [FormField; 6] = [
+    FormField { label: "Units or Rooms Rented Out", kind: FieldKind::Number },
+    FormField { label: "Monthly Rent per Unit", kind: FieldKind::Dollars },
+    FormField { label: "Vacancy", kind: FieldKind::Percent },
+    FormField { label: "Annual Rent Growth", kind: FieldKind::Percent },
+    FormField { label: "Management Fee (of rent collected)", kind: FieldKind::Percent },
+    FormField { label: "Extra Repairs per Unit (monthly)", kind: FieldKind::Dollars },
+];
+
+const REFINANCE_FIELDS: [FormField; 5] = [
+    FormField { label: "Refinance In (blank = never)", kind: FieldKind::Month },
+    FormField { label: "New Rate", kind: FieldKind::Percent },
+    FormField { label: "New Term", kind: FieldKind::Years },
+    FormField { label: "Closing Costs (paid in cash)", kind: FieldKind::Dollars },
+    FormField { label: "Cash Out", kind: FieldKind::Dollars },
+];
+
+const PREPAYMENT_FIELDS: [FormField; 6] = [
+    FormField { label: "One-time Prepayment", kind: FieldKind::Dollars },
+    FormField { label: "One-time Prepayment Paid In", kind: FieldKind::Month },
+    FormField { label: "Recurring Prepayment", kind: FieldKind::Dollars },
+    FormField { label: "First Recurring Prepayment In", kind: FieldKind::Month },
+    FormField { label: "Repeat Every", kind: FieldKind::Months },
+    FormField { label: "Prepayment CSV (month,amount[,every]) - adds to the above", kind: FieldKind::Text },
+];
+
+const INFLATION_FIELDS: [FormField; 7] = [
+    FormField { label: "General Inflation", kind: FieldKind::Percent },
+    FormField { label: "HOA Escalation (blank = inflation)", kind: FieldKind::Percent },
+    FormField { label: "Fixed Property Tax Escalation (blank = inflation)", kind: FieldKind::Percent },
+    FormField { label: "Fixed Insurance Escalation (blank = inflation)", kind: FieldKind::Percent },
+    FormField { label: "Fixed Maintenance Escalation (blank = inflation)", kind: FieldKind::Percent },
+    FormField { label: "Fixed PMI Escalation (blank = inflation)", kind: FieldKind::Percent },
+    FormField { label: "Present Value Discount Rate (blank = inflation)", kind: FieldKind::Percent },
+];
+
+const ASSESSMENT_FIELDS: [FormField; 4] = [
+    FormField { label: "Annual Assessment Cap (blank = market value)", kind: FieldKind::Percent },
+    FormField { label: "Reassessment Cycle (blank = every year)", kind: FieldKind::Years },
+    FormField { label: "Homestead Exemption", kind: FieldKind::Dollars },
+    FormField { label: "Senior Freeze From Year of Ownership (blank = none)", kind: FieldKind::Years },
+];
+
+const TAX_FIELDS: [FormField; 6] = [
+    FormField { label: "Marginal Federal Tax Rate", kind: FieldKind::Percent },
+    FormField { label: "Standard Deduction", kind: FieldKind::Dollars },
+    FormField { label: "SALT Deduction Cap", kind: FieldKind::Dollars },
+    FormField { label: "Mortgage Debt Limit", kind: FieldKind::Dollars },
+    FormField { label: "State & Local Income Tax (annual)", kind: FieldKind::Dollars },
+    FormField { label: "Other Itemized Deductions (annual)", kind: FieldKind::Dollars },
+];
+
+/// The first `CLOSING_ITEM_COUNT` closing fields are itemized costs named by their label.
+const CLOSING_ITEM_COUNT: usize = 5;
+
+const CLOSING_FIELDS: [FormField; 9] = [
+    FormField { label: "Lender & Origination Fees", kind: FieldKind::Dollars },
+    FormField { label: "Appraisal & Inspection", kind: FieldKind::Dollars },
+    FormField { label: "Title & Escrow", kind: FieldKind::Dollars },
+    FormField { label: "Recording & Transfer Taxes", kind: FieldKind::Dollars },
+    FormField { label: "Prepaids & Other", kind: FieldKind::Dollars },
+    FormField { label: "Discount Points (buy down the rate)", kind: FieldKind::Points },
+    FormField { label: "Lender Credit (raises the rate)", kind: FieldKind::Points },
+    FormField { label: "Rate Change per Point", kind: FieldKind::Percent },
+    FormField { label: "Seller Concessions", kind: FieldKind::Dollars },
+];
+
+const ARM_FIELDS: [FormField; 9] = [
+    FormField { label: "Initial Fixed Period", kind: FieldKind::Years },
+    FormField { label: "Adjustment Period", kind: FieldKind::Months },
+    FormField { label: "Index Rate", kind: FieldKind::Percent },
+    FormField { label: "Index CSV (month,rate%) - overrides index rate", kind: FieldKind::Text },
+    FormField { label: "Margin", kind: FieldKind::Percent },
+    FormField { label: "Initial Adjustment Cap", kind: FieldKind::Percent },
+    FormField { label: "Periodic Cap", kind: FieldKind::Percent },
+    FormField { label: "Lifetime Cap (above initial rate)", kind: FieldKind::Percent },
+    FormField { label: "Rate Floor", kind: FieldKind::Percent },
+];
+
+fn parse_cost(use_percent: bool, percent: &str, amount: &str) -> Result<Cost> {
+    Ok(if use_percent {
+        Cost::Rate(percent.parse::<f64>()? / 100.0)
+    } else {
+        Cost::Fixed(amount.parse()?)
+    })
+}
+
+/// Parses an input that may be left blank to mean "not set".
+fn parse_optional<T>(value: &str) -> Result<Option<T>>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(if value.is_empty() { None } else { Some(value.parse()?) })
+}
+
+fn parse_optional_percent(value: &str) -> Result<Option<f64>> {
+    Ok(parse_optional::<f64>(value)?.map(|percent| percent / 100.0))
+}
+
+fn years_to_months(years: f64) -> u32 {
+    (years * 12.0).round() as u32
+}
+
+impl App {
+    fn calculate_mortgage(&mut self) -> Result<()> {
+        let scenario = self.inputs.to_scenario()?;
+        self.schedule = Some(simulate(&scenario));
+        self.prepayment_comparison = (!scenario.prepayments.is_empty()).then(|| compare_prepayments(&scenario));
+        self.refinance = analyze_refinance(&scenario);
+        self.frequency_comparison =
+            (scenario.payment_frequency != PaymentFrequency::Monthly).then(|| compare_frequencies(&scenario));
+        let discount_rate = parse_optional_percent(&self.inputs.discount_rate)?.unwrap_or(scenario.inflation_rate);
+        self.real_schedule = self.schedule.as_ref().map(|schedule| schedule.in_real_dollars(scenario.inflation_rate));
+        self.present_values = self.schedule.as_ref().map(|schedule| schedule.present_values(discount_rate));
         Ok(())
     }
-    
-    fn export_to_csv(&self, filename: &str) -> Result<()> {
-        let mut file = File::create(filename)?;
-        
-        // Write header
-        writeln!(file, "Month,Interest,Principal,Extra Principal,Repair Costs,HOA,Taxes,Insurance,PMI,Actual Payment,Cost of Capital,Waste Cost,Cost,Debt,Interest Rate,House Cost,Equity")?;
-        
-        // Write data rows
-        for row in &self.spreadsheet_data {
-            writeln!(
-                file,
-                "{},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.4},{:.2},{:.2}",
-                row.month,
-                row.interest,
-                row.principal,
-                row.extra_principal,
-                row.repair_costs,
-                row.hoa,
-                row.taxes,
-                row.insurance,
-                row.pmi,
-                row.actual_payment,
-                row.cost_of_capital,
-                row.waste_cost,
-                row.cost,
-                row.debt,
-                row.interest_rate,
-                row.house_cost,
-                row.equity
-            )?;
+
+    fn compare_rent(&mut self) -> Result<()> {
+        let scenario = self.inputs.to_scenario()?;
+        let rent = self.inputs.to_rent_scenario()?;
+        self.rent_comparison = Some(compare_rent(&scenario, &rent));
+        Ok(())
+    }
+
+    fn analyze_sale(&mut self) -> Result<()> {
+        let scenario = self.inputs.to_scenario()?;
+        let sale = self.inputs.to_sale_assumptions()?;
+        let schedule = simulate(&scenario);
+        self.sale = Some(
+            analyze_sale(&scenario, &schedule, &sale)
+                .ok_or_else(|| anyhow::anyhow!("holding period must be within the loan schedule"))?,
+        );
+        Ok(())
+    }
+
+    fn run_monte_carlo(&mut self) -> Result<()> {
+        let scenario = self.inputs.to_scenario()?;
+        let assumptions = self.inputs.to_monte_carlo_assumptions(&scenario)?;
+        self.monte_carlo = Some(
+            run_monte_carlo(&scenario, &assumptions)
+                .ok_or_else(|| anyhow::anyhow!("need at least one path and a valid set of correlations"))?,
+        );
+        Ok(())
+    }
+
+    fn solve_affordability(&mut self) -> Result<()> {
+        let scenario = self.inputs.to_scenario()?;
+        let inputs = self.inputs.to_affordability_inputs()?;
+        self.affordability = Some(
+            solve_affordability(&scenario, &inputs)
+                .map_err(|constraint| anyhow::anyhow!("no purchase price meets the {} limit", constraint))?,
+        );
+        Ok(())
+    }
+
+    fn goal_seek(&mut self) -> Result<()> {
+        let scenario = self.inputs.to_scenario()?;
+        let output = self.inputs.to_goal_output()?;
+        let target = self.inputs.goal_target.parse()?;
+        self.goal_seek = Some(goal_seek(&scenario, self.inputs.goal_input, output, target));
+        Ok(())
+    }
+
+    fn analyze_sensitivity(&mut self) -> Result<()> {
+        let scenario = self.inputs.to_scenario()?;
+        let change = self.inputs.sensitivity_change.parse::<f64>()? / 100.0;
+        self.sensitivity = Some(analyze_sensitivity(&scenario, change));
+        Ok(())
+    }
+
+    /// The schedule as displayed: nominal, or in today's dollars.
+    fn shown_schedule(&self) -> Option<&Schedule> {
+        if self.real_dollars {
+            self.real_schedule.as_ref()
+        } else {
+            self.schedule.as_ref()
         }
-        
-        // Write summary if available
-        if let Some(summary) = &self.summary {
-            writeln!(file)?;
-            writeln!(file, "Summary Statistics")?;
-            writeln!(file, "Total Interest Paid,{:.2}", summary.total_interest_paid)?;
-            writeln!(file, "Total Principal Paid,{:.2}", summary.total_principal_paid)?;
-            writeln!(file, "Total Taxes Paid,{:.2}", summary.total_taxes_paid)?;
-            writeln!(file, "Total Insurance Paid,{:.2}", summary.total_insurance_paid)?;
-            writeln!(file, "Total Maintenance Paid,{:.2}", summary.total_maintenance_paid)?;
-            writeln!(file, "Total PMI Paid,{:.2}", summary.total_pmi_paid)?;
-            writeln!(file, "Total HOA Paid,{:.2}", summary.total_hoa_paid)?;
-            writeln!(file, "Total Payments,{:.2}", summary.total_payments)?;
-            writeln!(file, "Total Cost of Capital,{:.2}", summary.total_cost_of_capital)?;
-            writeln!(file, "Total Waste Cost,{:.2}", summary.total_waste_cost)?;
-            writeln!(file, "Final House Value,{:.2}", summary.final_house_value)?;
-            writeln!(file, "Final Equity,{:.2}", summary.final_equity)?;
-            writeln!(file, "Months to Payoff,{}", summary.months_to_payoff)?;
-            writeln!(file, "Effective Interest Rate,{:.4}", summary.effective_interest_rate)?;
+    }
+
+    fn rows(&self) -> &[MortgageRow] {
+        self.shown_schedule().map_or(&[], |schedule| &schedule.rows)
+    }
+
+    fn export_to_csv(&self, filename: &str) -> Result<()> {
+        if let Some(schedule) = self.shown_schedule() {
+            schedule.write_csv(File::create(filename)?)?;
         }
-        
         Ok(())
     }
 }
@@ -441,14 +1137,27 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
             match app.screen {
                 Screen::HouseValue => handle_house_value_input(&mut app, key)?,
                 Screen::DownPayment => handle_down_payment_input(&mut app, key)?,
+                Screen::LoanProgram => handle_loan_program_input(&mut app, key)?,
                 Screen::HOAFee => handle_hoa_input(&mut app, key)?,
                 Screen::InterestRate => handle_interest_rate_input(&mut app, key)?,
+                Screen::RateType => handle_rate_type_input(&mut app, key)?,
+                Screen::ClosingCosts => handle_closing_costs_input(&mut app, key)?,
                 Screen::PropertyTax => handle_property_tax_input(&mut app, key)?,
+                Screen::Assessment => handle_assessment_input(&mut app, key)?,
                 Screen::Insurance => handle_insurance_input(&mut app, key)?,
                 Screen::Maintenance => handle_maintenance_input(&mut app, key)?,
-                Screen::PMI => handle_pmi_input(&mut app, key)?,
+                Screen::RentalIncome => handle_rental_income_input(&mut app, key)?,
+                Screen::Pmi => handle_pmi_input(&mut app, key)?,
                 Screen::HouseAppreciation => handle_house_appreciation_input(&mut app, key)?,
+                Screen::Inflation => handle_inflation_input(&mut app, key)?,
+                Screen::InvestmentReturn => handle_investment_return_input(&mut app, key)?,
+                Screen::IncomeTax => handle_income_tax_input(&mut app, key)?,
                 Screen::LoanTerm => handle_loan_term_input(&mut app, key)?,
+                Screen::LoanStructure => handle_loan_structure_input(&mut app, key)?,
+                Screen::SecondLien => handle_second_lien_input(&mut app, key)?,
+                Screen::PaymentFrequency => handle_payment_frequency_input(&mut app, key)?,
+                Screen::Prepayments => handle_prepayments_input(&mut app, key)?,
+                Screen::Refinance => handle_refinance_input(&mut app, key)?,
                 Screen::ExtraPrincipal => handle_extra_principal_input(&mut app, key)?,
                 Screen::Spreadsheet => {
                     if handle_spreadsheet_input(&mut app, key)? {
@@ -460,6 +1169,47 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
                         return Ok(());
                     }
                 }
+                Screen::RentInputs => handle_rent_inputs_input(&mut app, key)?,
+                Screen::SaleInputs => handle_sale_inputs_input(&mut app, key)?,
+                Screen::Equity => {
+                    if handle_equity_input(&mut app, key)? {
+                        return Ok(());
+                    }
+                }
+                Screen::SaleResult => {
+                    if handle_sale_result_input(&mut app, key)? {
+                        return Ok(());
+                    }
+                }
+                Screen::GoalSeekInputs => handle_goal_seek_inputs_input(&mut app, key)?,
+                Screen::GoalSeekResult => {
+                    if handle_goal_seek_result_input(&mut app, key)? {
+                        return Ok(());
+                    }
+                }
+                Screen::AffordabilityInputs => handle_affordability_inputs_input(&mut app, key)?,
+                Screen::AffordabilityResult => {
+                    if handle_affordability_result_input(&mut app, key)? {
+                        return Ok(());
+                    }
+                }
+                Screen::SensitivityInputs => handle_sensitivity_inputs_input(&mut app, key)?,
+                Screen::Tornado => {
+                    if handle_tornado_input(&mut app, key)? {
+                        return Ok(());
+                    }
+                }
+                Screen::MonteCarloInputs => handle_monte_carlo_inputs_input(&mut app, key)?,
+                Screen::MonteCarloResult => {
+                    if handle_monte_carlo_result_input(&mut app, key)? {
+                        return Ok(());
+                    }
+                }
+                Screen::RentComparison => {
+                    if handle_rent_comparison_input(&mut app, key)? {
+                        return Ok(());
+                    }
+                }
             }
         }
     }
@@ -473,10 +1223,8 @@ fn handle_house_value_input(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Backspace => {
             app.inputs.house_value.pop();
         }
-        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
-            if !app.inputs.house_value.is_empty() {
-                app.screen = Screen::DownPayment;
-            }
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right if !app.inputs.house_value.is_empty() => {
+            app.screen = Screen::DownPayment;
         }
         KeyCode::Esc | KeyCode::Char('q') => std::process::exit(0),
         _ => {}
@@ -510,7 +1258,7 @@ fn handle_down_payment_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 !app.inputs.down_payment_amount.is_empty()
             };
             if valid {
-                app.screen = Screen::HOAFee;
+                app.screen = Screen::LoanProgram;
             }
         }
         KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::HouseValue,
@@ -519,6 +1267,36 @@ fn handle_down_payment_input(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+fn handle_loan_program_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Tab => {
+            app.inputs.loan_program = match app.inputs.loan_program {
+                LoanProgram::Conventional => LoanProgram::Fha,
+                LoanProgram::Fha => LoanProgram::Va {
+                    subsequent_use: false,
+                    exempt: false,
+                },
+                LoanProgram::Va { .. } => LoanProgram::Usda,
+                LoanProgram::Usda => LoanProgram::Conventional,
+            };
+        }
+        KeyCode::Char('s') => {
+            if let LoanProgram::Va { subsequent_use, .. } = &mut app.inputs.loan_program {
+                *subsequent_use = !*subsequent_use;
+            }
+        }
+        KeyCode::Char('x') => {
+            if let LoanProgram::Va { exempt, .. } = &mut app.inputs.loan_program {
+                *exempt = !*exempt;
+            }
+        }
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => app.screen = Screen::HOAFee,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::DownPayment,
+        _ => {}
+    }
+    Ok(())
+}
+
 fn handle_hoa_input(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Char(c) if c.is_numeric() || c == '.' => {
@@ -530,7 +1308,7 @@ fn handle_hoa_input(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
             app.screen = Screen::InterestRate;
         }
-        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::DownPayment,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::LoanProgram,
         _ => {}
     }
     Ok(())
@@ -544,10 +1322,8 @@ fn handle_interest_rate_input(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Backspace => {
             app.inputs.interest_rate.pop();
         }
-        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
-            if !app.inputs.interest_rate.is_empty() {
-                app.screen = Screen::PropertyTax;
-            }
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right if !app.inputs.interest_rate.is_empty() => {
+            app.screen = Screen::RateType;
         }
         KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::HOAFee,
         _ => {}
@@ -555,6 +1331,33 @@ fn handle_interest_rate_input(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+fn handle_rate_type_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    if key.code == KeyCode::Tab {
+        app.inputs.use_arm = !app.inputs.use_arm;
+        return Ok(());
+    }
+    let action = if app.inputs.use_arm {
+        handle_form_input(&mut app.inputs.arm_values(), &ARM_FIELDS, &mut app.form_field, key)
+    } else {
+        handle_form_input(&mut [], &[], &mut app.form_field, key)
+    };
+    match action {
+        FormAction::Next => app.screen = Screen::ClosingCosts,
+        FormAction::Back => app.screen = Screen::InterestRate,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_closing_costs_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.closing_values(), &CLOSING_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => app.screen = Screen::PropertyTax,
+        FormAction::Back => app.screen = Screen::RateType,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
 fn handle_property_tax_input(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Tab => {
@@ -581,15 +1384,29 @@ fn handle_property_tax_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 !app.inputs.property_tax_amount.is_empty()
             };
             if valid {
-                app.screen = Screen::Insurance;
+                app.screen = Screen::Assessment;
             }
         }
-        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::InterestRate,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::ClosingCosts,
         _ => {}
     }
     Ok(())
 }
 
+fn handle_assessment_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let action = if app.inputs.use_property_tax_percent {
+        handle_form_input(&mut app.inputs.assessment_values(), &ASSESSMENT_FIELDS, &mut app.form_field, key)
+    } else {
+        handle_form_input(&mut [], &[], &mut app.form_field, key)
+    };
+    match action {
+        FormAction::Next => app.screen = Screen::Insurance,
+        FormAction::Back => app.screen = Screen::PropertyTax,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
 fn handle_insurance_input(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Tab => {
@@ -619,7 +1436,7 @@ fn handle_insurance_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.screen = Screen::Maintenance;
             }
         }
-        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::PropertyTax,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::Assessment,
         _ => {}
     }
     Ok(())
@@ -651,7 +1468,7 @@ fn handle_maintenance_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 !app.inputs.maintenance_amount.is_empty()
             };
             if valid {
-                app.screen = Screen::PMI;
+                app.screen = Screen::RentalIncome;
             }
         }
         KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::Insurance,
@@ -665,6 +1482,12 @@ fn handle_pmi_input(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Tab => {
             app.inputs.use_pmi_percent = !app.inputs.use_pmi_percent;
         }
+        KeyCode::Char('r') => {
+            app.inputs.pmi_borrower_request = !app.inputs.pmi_borrower_request;
+        }
+        KeyCode::Char('a') => {
+            app.inputs.pmi_new_appraisal = !app.inputs.pmi_new_appraisal;
+        }
         KeyCode::Char(c) if c.is_numeric() || c == '.' => {
             if app.inputs.use_pmi_percent {
                 app.inputs.pmi_percent.push(c);
@@ -689,7 +1512,7 @@ fn handle_pmi_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.screen = Screen::HouseAppreciation;
             }
         }
-        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::Maintenance,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::RentalIncome,
         _ => {}
     }
     Ok(())
@@ -703,36 +1526,174 @@ fn handle_house_appreciation_input(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Backspace => {
             app.inputs.house_appreciation_rate.pop();
         }
-        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
-            if !app.inputs.house_appreciation_rate.is_empty() {
-                app.screen = Screen::LoanTerm;
-            }
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right if !app.inputs.house_appreciation_rate.is_empty() => {
+            app.screen = Screen::Inflation;
         }
-        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::PMI,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::Pmi,
         _ => {}
     }
     Ok(())
 }
 
-fn handle_loan_term_input(app: &mut App, key: KeyEvent) -> Result<()> {
-    match key.code {
-        KeyCode::Char(c) if c.is_numeric() => {
-            app.inputs.loan_term_years.push(c);
-        }
-        KeyCode::Backspace => {
-            app.inputs.loan_term_years.pop();
-        }
-        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
-            if !app.inputs.loan_term_years.is_empty() {
-                app.screen = Screen::ExtraPrincipal;
+fn handle_inflation_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.inflation_values(), &INFLATION_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => app.screen = Screen::InvestmentReturn,
+        FormAction::Back => app.screen = Screen::HouseAppreciation,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_investment_return_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.investment_values(), &INVESTMENT_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => app.screen = Screen::IncomeTax,
+        FormAction::Back => app.screen = Screen::Inflation,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_income_tax_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    if key.code == KeyCode::Tab {
+        let next = match app.inputs.filing_status {
+            None => Some(FilingStatus::Single),
+            Some(FilingStatus::Single) => Some(FilingStatus::MarriedFilingJointly),
+            Some(FilingStatus::MarriedFilingJointly) => Some(FilingStatus::MarriedFilingSeparately),
+            Some(FilingStatus::MarriedFilingSeparately) => Some(FilingStatus::HeadOfHousehold),
+            Some(FilingStatus::HeadOfHousehold) => None,
+        };
+        app.inputs.set_filing_status(next);
+        return Ok(());
+    }
+    let action = if app.inputs.filing_status.is_some() {
+        handle_form_input(&mut app.inputs.tax_values(), &TAX_FIELDS, &mut app.form_field, key)
+    } else {
+        handle_form_input(&mut [], &[], &mut app.form_field, key)
+    };
+    match action {
+        FormAction::Next => app.screen = Screen::LoanTerm,
+        FormAction::Back => app.screen = Screen::InvestmentReturn,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_loan_term_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Tab => {
+            app.inputs.use_loan_term_years = !app.inputs.use_loan_term_years;
+        }
+        KeyCode::Char(c) if c.is_numeric() || (c == '.' && app.inputs.use_loan_term_years) => {
+            if app.inputs.use_loan_term_years {
+                app.inputs.loan_term_years.push(c);
+            } else {
+                app.inputs.loan_term_months.push(c);
+            }
+        }
+        KeyCode::Backspace => {
+            if app.inputs.use_loan_term_years {
+                app.inputs.loan_term_years.pop();
+            } else {
+                app.inputs.loan_term_months.pop();
+            }
+        }
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
+            let valid = if app.inputs.use_loan_term_years {
+                !app.inputs.loan_term_years.is_empty()
+            } else {
+                !app.inputs.loan_term_months.is_empty()
+            };
+            if valid {
+                app.screen = Screen::LoanStructure;
             }
         }
-        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::HouseAppreciation,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::IncomeTax,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_loan_structure_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.loan_structure_values(), &LOAN_STRUCTURE_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => app.screen = Screen::SecondLien,
+        FormAction::Back => app.screen = Screen::LoanTerm,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_second_lien_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    if key.code == KeyCode::Tab {
+        app.inputs.second_lien = match app.inputs.second_lien {
+            None => Some(SecondLienType::Fixed),
+            Some(SecondLienType::Fixed) => Some(SecondLienType::Heloc),
+            Some(SecondLienType::Heloc) => None,
+        };
+        app.form_field = 0;
+        return Ok(());
+    }
+    let action = match app.inputs.second_lien {
+        None => handle_form_input(&mut [], &[], &mut app.form_field, key),
+        Some(SecondLienType::Fixed) => {
+            handle_form_input(&mut app.inputs.fixed_second_values(), &FIXED_SECOND_FIELDS, &mut app.form_field, key)
+        }
+        Some(SecondLienType::Heloc) => {
+            handle_form_input(&mut app.inputs.heloc_values(), &HELOC_FIELDS, &mut app.form_field, key)
+        }
+    };
+    match action {
+        FormAction::Next => app.screen = Screen::PaymentFrequency,
+        FormAction::Back => app.screen = Screen::LoanStructure,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_payment_frequency_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Tab => {
+            let all = PaymentFrequency::ALL;
+            let current = all.iter().position(|&f| f == app.inputs.payment_frequency).unwrap_or(0);
+            app.inputs.payment_frequency = all[(current + 1) % all.len()];
+        }
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => app.screen = Screen::Prepayments,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::SecondLien,
         _ => {}
     }
     Ok(())
 }
 
+fn handle_prepayments_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    if key.code == KeyCode::Tab {
+        app.inputs.recast = !app.inputs.recast;
+        return Ok(());
+    }
+    match handle_form_input(&mut app.inputs.prepayment_values(), &PREPAYMENT_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => app.screen = Screen::Refinance,
+        FormAction::Back => app.screen = Screen::PaymentFrequency,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_rental_income_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.rental_values(), &RENTAL_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => app.screen = Screen::Pmi,
+        FormAction::Back => app.screen = Screen::Maintenance,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_refinance_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.refinance_values(), &REFINANCE_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => app.screen = Screen::ExtraPrincipal,
+        FormAction::Back => app.screen = Screen::Prepayments,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
 fn handle_extra_principal_input(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Char(c) if c.is_numeric() || c == '.' => {
@@ -741,17 +1702,21 @@ fn handle_extra_principal_input(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Backspace => {
             app.inputs.extra_principal_payment.pop();
         }
-        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
-            if !app.inputs.extra_principal_payment.is_empty() {
-                if let Err(e) = app.calculate_mortgage() {
-                    eprintln!("Error calculating mortgage: {}", e);
-                } else {
-                    app.screen = Screen::Spreadsheet;
-                    app.table_state.select(Some(0));
-                }
+        KeyCode::Char('b') => {
+            app.inputs.rounding = match app.inputs.rounding {
+                Rounding::HalfUp => Rounding::HalfEven,
+                Rounding::HalfEven => Rounding::HalfUp,
+            };
+        }
+        KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right if !app.inputs.extra_principal_payment.is_empty() => {
+            if let Err(e) = app.calculate_mortgage() {
+                eprintln!("Error calculating mortgage: {}", e);
+            } else {
+                app.screen = Screen::Spreadsheet;
+                app.table_state.select(Some(0));
             }
         }
-        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::LoanTerm,
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => app.screen = Screen::Refinance,
         _ => {}
     }
     Ok(())
@@ -759,13 +1724,14 @@ fn handle_extra_principal_input(app: &mut App, key: KeyEvent) -> Result<()> {
 
 fn handle_spreadsheet_input(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
-        KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(true),
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
         KeyCode::Esc => {
             app.screen = Screen::ExtraPrincipal;
             Ok(false)
         }
         KeyCode::Char('s') | KeyCode::Char('S') => {
             app.screen = Screen::Summary;
+            app.summary_scroll = 0;
             Ok(false)
         }
         KeyCode::Char('e') | KeyCode::Char('E') => {
@@ -780,53 +1746,111 @@ fn handle_spreadsheet_input(app: &mut App, key: KeyEvent) -> Result<bool> {
             }
             Ok(false)
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            let current = app.table_state.selected().unwrap_or(0);
-            if current < app.spreadsheet_data.len() - 1 {
-                app.table_state.select(Some(current + 1));
-            }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.screen = Screen::RentInputs;
             Ok(false)
         }
-        KeyCode::Up | KeyCode::Char('k') => {
-            let current = app.table_state.selected().unwrap_or(0);
-            if current > 0 {
-                app.table_state.select(Some(current - 1));
-            }
+        KeyCode::Char('p') | KeyCode::Char('P') => {
+            app.screen = Screen::SaleInputs;
             Ok(false)
         }
-        KeyCode::PageDown | KeyCode::Char('d') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
-            let current = app.table_state.selected().unwrap_or(0);
-            let new_pos = (current + 10).min(app.spreadsheet_data.len() - 1);
-            app.table_state.select(Some(new_pos));
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.screen = Screen::Equity;
+            app.equity_table_state.select(Some(0));
             Ok(false)
         }
-        KeyCode::PageUp | KeyCode::Char('u') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
-            let current = app.table_state.selected().unwrap_or(0);
-            let new_pos = current.saturating_sub(10);
-            app.table_state.select(Some(new_pos));
+        KeyCode::Char('m') | KeyCode::Char('M') => {
+            app.screen = Screen::MonteCarloInputs;
             Ok(false)
         }
-        KeyCode::Char('g') => {
-            app.table_state.select(Some(0));
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            app.screen = Screen::SensitivityInputs;
             Ok(false)
         }
-        KeyCode::Char('G') => {
-            if !app.spreadsheet_data.is_empty() {
-                app.table_state.select(Some(app.spreadsheet_data.len() - 1));
-            }
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            app.screen = Screen::AffordabilityInputs;
+            Ok(false)
+        }
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            app.screen = Screen::GoalSeekInputs;
+            Ok(false)
+        }
+        // Ctrl+d pages down instead
+        KeyCode::Char('d') | KeyCode::Char('D') if !key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+            app.real_dollars = !app.real_dollars;
             Ok(false)
         }
         KeyCode::Char('h') | KeyCode::Left => {
             app.screen = Screen::ExtraPrincipal;
             Ok(false)
         }
-        _ => Ok(false),
+        _ => {
+            let len = app.rows().len();
+            navigate_table(&mut app.table_state, len, key);
+            Ok(false)
+        }
+    }
+}
+
+/// Row navigation shared by the table screens: j/k or ↑/↓, Ctrl+d/u or PgDn/PgUp, g/G.
+fn navigate_table(state: &mut TableState, len: usize, key: KeyEvent) {
+    if len == 0 {
+        return;
+    }
+    let current = state.selected().unwrap_or(0);
+    let control = key.modifiers.contains(event::KeyModifiers::CONTROL);
+    let new_pos = match key.code {
+        KeyCode::Down | KeyCode::Char('j') => (current + 1).min(len - 1),
+        KeyCode::Up | KeyCode::Char('k') => current.saturating_sub(1),
+        KeyCode::PageDown => (current + 10).min(len - 1),
+        KeyCode::Char('d') if control => (current + 10).min(len - 1),
+        KeyCode::PageUp => current.saturating_sub(10),
+        KeyCode::Char('u') if control => current.saturating_sub(10),
+        KeyCode::Char('g') => 0,
+        KeyCode::Char('G') => len - 1,
+        _ => return,
+    };
+    state.select(Some(new_pos));
+}
+
+fn handle_rent_inputs_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.rent_values(), &RENT_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => {
+            if let Err(e) = app.compare_rent() {
+                eprintln!("Error comparing rent: {}", e);
+            } else {
+                app.screen = Screen::RentComparison;
+                app.rent_table_state.select(Some(0));
+            }
+        }
+        FormAction::Back => app.screen = Screen::Spreadsheet,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_rent_comparison_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
+            app.screen = Screen::Spreadsheet;
+            Ok(false)
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.screen = Screen::RentInputs;
+            Ok(false)
+        }
+        _ => {
+            let len = app.rent_comparison.as_ref().map_or(0, |comparison| comparison.rows.len());
+            navigate_table(&mut app.rent_table_state, len, key);
+            Ok(false)
+        }
     }
 }
 
 fn handle_summary_input(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
-        KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(true),
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
         KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
             app.screen = Screen::Spreadsheet;
             Ok(false)
@@ -844,90 +1868,419 @@ fn handle_summary_input(app: &mut App, key: KeyEvent) -> Result<bool> {
             }
             Ok(false)
         }
-        _ => Ok(false),
+        KeyCode::Char('d') | KeyCode::Char('D') if !key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+            app.real_dollars = !app.real_dollars;
+            Ok(false)
+        }
+        _ => {
+            // Scrolls like the tables; rendering keeps it within the text
+            let control = key.modifiers.contains(event::KeyModifiers::CONTROL);
+            app.summary_scroll = match key.code {
+                KeyCode::Down | KeyCode::Char('j') => app.summary_scroll.saturating_add(1),
+                KeyCode::Up | KeyCode::Char('k') => app.summary_scroll.saturating_sub(1),
+                KeyCode::PageDown => app.summary_scroll.saturating_add(10),
+                KeyCode::Char('d') if control => app.summary_scroll.saturating_add(10),
+                KeyCode::PageUp => app.summary_scroll.saturating_sub(10),
+                KeyCode::Char('u') if control => app.summary_scroll.saturating_sub(10),
+                KeyCode::Char('g') => 0,
+                KeyCode::Char('G') => u16::MAX,
+                _ => app.summary_scroll,
+            };
+            Ok(false)
+        }
     }
 }
 
-fn ui(f: &mut Frame, app: &mut App) {
-    match app.screen {
-        Screen::HouseValue => render_house_value_screen(f, app),
-        Screen::DownPayment => render_down_payment_screen(f, app),
-        Screen::HOAFee => render_hoa_screen(f, app),
-        Screen::InterestRate => render_interest_rate_screen(f, app),
-        Screen::PropertyTax => render_property_tax_screen(f, app),
-        Screen::Insurance => render_insurance_screen(f, app),
-        Screen::Maintenance => render_maintenance_screen(f, app),
-        Screen::PMI => render_pmi_screen(f, app),
-        Screen::HouseAppreciation => render_house_appreciation_screen(f, app),
-        Screen::LoanTerm => render_loan_term_screen(f, app),
-        Screen::ExtraPrincipal => render_extra_principal_screen(f, app),
-        Screen::Spreadsheet => render_spreadsheet_screen(f, app),
-        Screen::Summary => render_summary_screen(f, app),
+fn handle_equity_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
+            app.screen = Screen::Spreadsheet;
+            Ok(false)
+        }
+        _ => {
+            let len = app.shown_schedule().map_or(0, |schedule| schedule.summary.anniversaries.len());
+            navigate_table(&mut app.equity_table_state, len, key);
+            Ok(false)
+        }
     }
 }
 
-fn render_house_value_screen(f: &mut Frame, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(2)
-        .constraints(
-            [
-                Constraint::Length(3),
-                Constraint::Length(3),
-                Constraint::Min(1),
-            ]
-            .as_ref(),
-        )
-        .split(f.size());
+fn handle_sale_inputs_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.sale_values(), &SALE_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => {
+            if let Err(e) = app.analyze_sale() {
+                eprintln!("Error analyzing sale: {}", e);
+            } else {
+                app.screen = Screen::SaleResult;
+            }
+        }
+        FormAction::Back => app.screen = Screen::Spreadsheet,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
 
-    let title = Paragraph::new("Home Buyer Calculator")
-        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::BOTTOM));
-    f.render_widget(title, chunks[0]);
+fn handle_goal_seek_inputs_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Char('o') => {
+            app.inputs.goal_output = (app.inputs.goal_output + 1) % GOAL_OUTPUT_LABELS.len();
+            return Ok(());
+        }
+        KeyCode::Char('i') => {
+            let all = GoalInput::ALL;
+            let current = all.iter().position(|&input| input == app.inputs.goal_input).unwrap_or(0);
+            app.inputs.goal_input = all[(current + 1) % all.len()];
+            return Ok(());
+        }
+        _ => {}
+    }
+    match handle_form_input(&mut app.inputs.goal_values(), &GOAL_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => {
+            if let Err(e) = app.goal_seek() {
+                eprintln!("Error seeking goal: {}", e);
+            } else {
+                app.screen = Screen::GoalSeekResult;
+            }
+        }
+        FormAction::Back => app.screen = Screen::Spreadsheet,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
 
-    let input_block = Block::default()
-        .borders(Borders::ALL)
-        .title("What is the value of the house you're considering buying?");
-    
-    let input = Paragraph::new(format!("${}", app.inputs.house_value))
-        .style(Style::default().fg(Color::Yellow))
-        .block(input_block);
-    f.render_widget(input, chunks[1]);
+fn handle_goal_seek_result_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
+            app.screen = Screen::Spreadsheet;
+            Ok(false)
+        }
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            app.screen = Screen::GoalSeekInputs;
+            Ok(false)
+        }
+        KeyCode::Char('u') | KeyCode::Char('U') => {
+            if let Some(Ok(found)) = &app.goal_seek {
+                let value = found.input_value;
+                app.inputs.apply_goal_input(app.inputs.goal_input, value);
+                if let Err(e) = app.calculate_mortgage() {
+                    eprintln!("Error calculating mortgage: {}", e);
+                } else {
+                    app.screen = Screen::Spreadsheet;
+                    app.table_state.select(Some(0));
+                }
+            }
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
 
-    let help = Paragraph::new("Enter/l/→: continue | Esc/q: exit")
-        .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center);
-    f.render_widget(help, chunks[2]);
+fn handle_affordability_inputs_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.affordability_values(), &AFFORDABILITY_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => {
+            if let Err(e) = app.solve_affordability() {
+                eprintln!("Error solving affordability: {}", e);
+            } else {
+                app.screen = Screen::AffordabilityResult;
+            }
+        }
+        FormAction::Back => app.screen = Screen::Spreadsheet,
+        FormAction::Stay => {}
+    }
+    Ok(())
 }
 
-fn render_down_payment_screen(f: &mut Frame, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(2)
-        .constraints(
-            [
-                Constraint::Length(3),
-                Constraint::Length(7),
-                Constraint::Min(1),
-            ]
-            .as_ref(),
-        )
-        .split(f.size());
+fn handle_affordability_result_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
+            app.screen = Screen::Spreadsheet;
+            Ok(false)
+        }
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            app.screen = Screen::AffordabilityInputs;
+            Ok(false)
+        }
+        KeyCode::Char('u') | KeyCode::Char('U') => {
+            if let Some(affordability) = &app.affordability {
+                app.inputs.house_value = format!("{:.0}", affordability.max_price.floor());
+                if let Err(e) = app.calculate_mortgage() {
+                    eprintln!("Error calculating mortgage: {}", e);
+                } else {
+                    app.screen = Screen::Spreadsheet;
+                    app.table_state.select(Some(0));
+                }
+            }
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
 
-    let title = Paragraph::new("Home Buyer Calculator")
-        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::BOTTOM));
-    f.render_widget(title, chunks[0]);
+fn handle_sensitivity_inputs_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut [&mut app.inputs.sensitivity_change], &SENSITIVITY_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => {
+            if let Err(e) = app.analyze_sensitivity() {
+                eprintln!("Error analyzing sensitivity: {}", e);
+            } else {
+                app.screen = Screen::Tornado;
+            }
+        }
+        FormAction::Back => app.screen = Screen::Spreadsheet,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
 
-    let percent_value = format!("{}%", app.inputs.down_payment_percent);
-    let amount_value = format!("${}", app.inputs.down_payment_amount);
-    
-    let percent_option = if app.inputs.use_percent {
-        format!("▶ Percentage: {}", percent_value)
-    } else {
-        format!("  Percentage: {}", percent_value)
+fn handle_tornado_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
+            app.screen = Screen::Spreadsheet;
+            Ok(false)
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            app.screen = Screen::SensitivityInputs;
+            Ok(false)
+        }
+        KeyCode::Tab => {
+            let all = SensitivityMetric::ALL;
+            let current = all.iter().position(|&m| m == app.sensitivity_metric).unwrap_or(0);
+            app.sensitivity_metric = all[(current + 1) % all.len()];
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn handle_monte_carlo_inputs_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    match handle_form_input(&mut app.inputs.monte_carlo_values(), &MONTE_CARLO_FIELDS, &mut app.form_field, key) {
+        FormAction::Next => {
+            if let Err(e) = app.run_monte_carlo() {
+                eprintln!("Error running Monte Carlo simulation: {}", e);
+            } else {
+                app.screen = Screen::MonteCarloResult;
+            }
+        }
+        FormAction::Back => app.screen = Screen::Spreadsheet,
+        FormAction::Stay => {}
+    }
+    Ok(())
+}
+
+fn handle_monte_carlo_result_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
+            app.screen = Screen::Spreadsheet;
+            Ok(false)
+        }
+        KeyCode::Char('m') | KeyCode::Char('M') => {
+            app.screen = Screen::MonteCarloInputs;
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn handle_sale_result_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
+        KeyCode::Esc | KeyCode::Char('h') | KeyCode::Left => {
+            app.screen = Screen::Spreadsheet;
+            Ok(false)
+        }
+        KeyCode::Char('p') | KeyCode::Char('P') => {
+            app.screen = Screen::SaleInputs;
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Kind of second lien chosen on the Second Lien screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SecondLienType {
+    Fixed,
+    Heloc,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum FieldKind {
+    Percent,
+    Dollars,
+    Points,
+    Years,
+    Months,
+    Month,
+    Number,
+    Text,
+}
+
+struct FormField {
+    label: &'static str,
+    kind: FieldKind,
+}
+
+enum FormAction {
+    Stay,
+    Next,
+    Back,
+}
+
+/// Shared key handling for screens that edit several fields at once: ↑/↓ move between
+/// fields, Enter/→ continues and Esc/← goes back. Text fields accept any character, so
+/// `l`/`h` only navigate on numeric fields.
+fn handle_form_input(
+    values: &mut [&mut String],
+    fields: &[FormField],
+    selected: &mut usize,
+    key: KeyEvent,
+) -> FormAction {
+    let text = fields.get(*selected).is_some_and(|field| field.kind == FieldKind::Text);
+    let action = match key.code {
+        KeyCode::Up => {
+            *selected = selected.saturating_sub(1);
+            FormAction::Stay
+        }
+        KeyCode::Down => {
+            if *selected + 1 < values.len() {
+                *selected += 1;
+            }
+            FormAction::Stay
+        }
+        KeyCode::Enter | KeyCode::Right => FormAction::Next,
+        KeyCode::Esc | KeyCode::Left => FormAction::Back,
+        KeyCode::Char('l') if !text => FormAction::Next,
+        KeyCode::Char('h') if !text => FormAction::Back,
+        KeyCode::Char(c) if text || c.is_numeric() || c == '.' || c == '-' => {
+            if let Some(value) = values.get_mut(*selected) {
+                value.push(c);
+            }
+            FormAction::Stay
+        }
+        KeyCode::Backspace => {
+            if let Some(value) = values.get_mut(*selected) {
+                value.pop();
+            }
+            FormAction::Stay
+        }
+        _ => FormAction::Stay,
+    };
+    if !matches!(action, FormAction::Stay) {
+        *selected = 0;
+    }
+    action
+}
+
+fn ui(f: &mut Frame, app: &mut App) {
+    match app.screen {
+        Screen::HouseValue => render_house_value_screen(f, app),
+        Screen::DownPayment => render_down_payment_screen(f, app),
+        Screen::LoanProgram => render_loan_program_screen(f, app),
+        Screen::HOAFee => render_hoa_screen(f, app),
+        Screen::InterestRate => render_interest_rate_screen(f, app),
+        Screen::RateType => render_rate_type_screen(f, app),
+        Screen::ClosingCosts => render_closing_costs_screen(f, app),
+        Screen::PropertyTax => render_property_tax_screen(f, app),
+        Screen::Assessment => render_assessment_screen(f, app),
+        Screen::Insurance => render_insurance_screen(f, app),
+        Screen::Maintenance => render_maintenance_screen(f, app),
+        Screen::RentalIncome => render_rental_income_screen(f, app),
+        Screen::Pmi => render_pmi_screen(f, app),
+        Screen::HouseAppreciation => render_house_appreciation_screen(f, app),
+        Screen::Inflation => render_inflation_screen(f, app),
+        Screen::InvestmentReturn => render_investment_return_screen(f, app),
+        Screen::IncomeTax => render_income_tax_screen(f, app),
+        Screen::LoanTerm => render_loan_term_screen(f, app),
+        Screen::LoanStructure => render_loan_structure_screen(f, app),
+        Screen::SecondLien => render_second_lien_screen(f, app),
+        Screen::PaymentFrequency => render_payment_frequency_screen(f, app),
+        Screen::Prepayments => render_prepayments_screen(f, app),
+        Screen::Refinance => render_refinance_screen(f, app),
+        Screen::ExtraPrincipal => render_extra_principal_screen(f, app),
+        Screen::Spreadsheet => render_spreadsheet_screen(f, app),
+        Screen::Summary => render_summary_screen(f, app),
+        Screen::RentInputs => render_rent_inputs_screen(f, app),
+        Screen::RentComparison => render_rent_comparison_screen(f, app),
+        Screen::SaleInputs => render_sale_inputs_screen(f, app),
+        Screen::Equity => render_equity_screen(f, app),
+        Screen::SaleResult => render_sale_result_screen(f, app),
+        Screen::GoalSeekInputs => render_goal_seek_inputs_screen(f, app),
+        Screen::GoalSeekResult => render_goal_seek_result_screen(f, app),
+        Screen::AffordabilityInputs => render_affordability_inputs_screen(f, app),
+        Screen::AffordabilityResult => render_affordability_result_screen(f, app),
+        Screen::SensitivityInputs => render_sensitivity_inputs_screen(f, app),
+        Screen::Tornado => render_tornado_screen(f, app),
+        Screen::MonteCarloInputs => render_monte_carlo_inputs_screen(f, app),
+        Screen::MonteCarloResult => render_monte_carlo_result_screen(f, app),
+    }
+}
+
+fn render_house_value_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Home Buyer Calculator")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title("What is the value of the house you're considering buying?");
+    
+    let input = Paragraph::new(format!("${}", app.inputs.house_value))
+        .style(Style::default().fg(Color::Yellow))
+        .block(input_block);
+    f.render_widget(input, chunks[1]);
+
+    let help = Paragraph::new("Enter/l/→: continue | Esc/q: exit")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_down_payment_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(7),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Home Buyer Calculator")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    let percent_value = format!("{}%", app.inputs.down_payment_percent);
+    let amount_value = format!("${}", app.inputs.down_payment_amount);
+    
+    let percent_option = if app.inputs.use_percent {
+        format!("▶ Percentage: {}", percent_value)
+    } else {
+        format!("  Percentage: {}", percent_value)
     };
     
     let amount_option = if !app.inputs.use_percent {
@@ -949,91 +2302,905 @@ fn render_down_payment_screen(f: &mut Frame, app: &App) {
         }),
     ];
 
-    let input_block = Block::default()
-        .borders(Borders::ALL)
-        .title("Down Payment - Press Tab to switch between options");
-    
-    let input = Paragraph::new(options_text)
-        .block(input_block);
-    f.render_widget(input, chunks[1]);
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title("Down Payment - Press Tab to switch between options");
+    
+    let input = Paragraph::new(options_text)
+        .block(input_block);
+    f.render_widget(input, chunks[1]);
+
+    let help = Paragraph::new("Tab: toggle between % and $ | Enter/l/→: continue | Esc/h/←: back")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_loan_program_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(10),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Home Buyer Calculator")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    let program = app.inputs.loan_program;
+    let options = [
+        (matches!(program, LoanProgram::Conventional), "Conventional - PMI below 20% down"),
+        (matches!(program, LoanProgram::Fha), "FHA - 1.75% up-front MIP financed, plus annual MIP"),
+        (matches!(program, LoanProgram::Va { .. }), "VA - funding fee financed, no mortgage insurance"),
+        (matches!(program, LoanProgram::Usda), "USDA - 1% guarantee fee financed, plus 0.35% annual fee"),
+    ];
+
+    let mut options_text: Vec<Line> = options
+        .iter()
+        .map(|&(selected, label)| {
+            if selected {
+                Line::from(format!("▶ {}", label)).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            } else {
+                Line::from(format!("  {}", label)).style(Style::default().fg(Color::DarkGray))
+            }
+        })
+        .collect();
+
+    if let LoanProgram::Va { subsequent_use, exempt } = program {
+        let checkbox = |checked: bool| if checked { "[x]" } else { "[ ]" };
+        options_text.push(Line::from(""));
+        options_text.push(Line::from(format!("{} s: Subsequent use of VA entitlement", checkbox(subsequent_use))));
+        options_text.push(Line::from(format!("{} x: Exempt from funding fee (disability compensation)", checkbox(exempt))));
+    }
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title("Loan Program - Press Tab to switch between programs");
+    
+    let input = Paragraph::new(options_text)
+        .block(input_block);
+    f.render_widget(input, chunks[1]);
+
+    let help = Paragraph::new("Tab: next program | s/x: VA options | Enter/l/→: continue | Esc/h/←: back")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_hoa_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Home Buyer Calculator")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title("What is the monthly HOA fee? (0 if none)");
+    
+    let input = Paragraph::new(format!("${}", app.inputs.hoa_fee))
+        .style(Style::default().fg(Color::Yellow))
+        .block(input_block);
+    f.render_widget(input, chunks[1]);
+
+    let help = Paragraph::new("Enter/l/→: continue | Esc/h/←: back")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_interest_rate_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Home Buyer Calculator")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title("What is your expected interest rate? (%)");
+    
+    let input = Paragraph::new(format!("{}%", app.inputs.interest_rate))
+        .style(Style::default().fg(Color::Yellow))
+        .block(input_block);
+    f.render_widget(input, chunks[1]);
+
+    let help = Paragraph::new("Enter/l/→: calculate | Esc/h/←: back")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_spreadsheet_screen(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let header_cells = vec![
+        "Month", "Interest", "Principal", "Extra Principal", "Prepayment", "Balloon", "Refinance Costs", "Net Rent", "Repair Costs", 
+        "HOA", "Taxes", "Assessed Value", "Insurance", "PMI", "Actual Payment", 
+        "Cost of Capital", "Waste Cost", "After-Tax Waste", "Cost", "Debt", 
+        "Interest Rate", "House Cost", "Equity", "LTV", "2nd Lien Payment", "2nd Lien Debt", "CLTV"
+    ];
+    let header = Row::new(header_cells)
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .height(1);
+
+    let rows = app.rows().iter().map(|row| {
+        let cells = vec![
+            Cell::from(row.month.to_string()),
+            Cell::from(format!("${:.0}", row.interest)),
+            Cell::from(format!("${:.0}", row.principal)),
+            Cell::from(format!("${:.0}", row.extra_principal)),
+            Cell::from(format!("${:.0}", row.prepayment)),
+            if row.balloon > 0.0 {
+                Cell::from(format!("${:.0}", row.balloon)).style(Style::default().fg(Color::Red))
+            } else {
+                Cell::from(format!("${:.0}", row.balloon))
+            },
+            Cell::from(format!("${:.0}", row.refinance_costs)),
+            Cell::from(format!("${:.0}", row.rental_income)),
+            Cell::from(format!("${:.0}", row.repair_costs)),
+            Cell::from(format!("${:.0}", row.hoa)),
+            Cell::from(format!("${:.0}", row.taxes)),
+            Cell::from(format!("${:.0}", row.assessed_value)),
+            Cell::from(format!("${:.0}", row.insurance)),
+            match row.pmi_dropoff {
+                Some(reason) => Cell::from(format!("off: {}", reason)).style(Style::default().fg(Color::Green)),
+                None => Cell::from(format!("${:.0}", row.pmi)),
+            },
+            Cell::from(format!("${:.0}", row.actual_payment)),
+            Cell::from(format!("${:.0}", row.cost_of_capital)),
+            Cell::from(format!("${:.0}", row.waste_cost)),
+            Cell::from(format!("${:.0}", row.after_tax_waste_cost)),
+            Cell::from(format!("${:.0}", row.cost)),
+            Cell::from(format!("${:.0}", row.debt)),
+            Cell::from(format!("{:.2}%", row.interest_rate * 100.0)),
+            Cell::from(format!("${:.0}", row.house_cost)),
+            Cell::from(format!("${:.0}", row.equity)),
+            Cell::from(format!("{:.1}%", row.ltv * 100.0)),
+            Cell::from(format!("${:.0}", row.second_lien_interest + row.second_lien_principal)),
+            Cell::from(format!("${:.0}", row.second_lien_debt)),
+            Cell::from(format!("{:.1}%", row.combined_ltv * 100.0)),
+        ];
+        Row::new(cells).height(1)
+    });
+
+    let widths = [
+        Constraint::Length(6),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(15),
+        Constraint::Length(11),
+        Constraint::Length(11),
+        Constraint::Length(16),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(10),
+        Constraint::Length(15),
+        Constraint::Length(10),
+        Constraint::Length(14),
+        Constraint::Length(14),
+        Constraint::Length(15),
+        Constraint::Length(11),
+        Constraint::Length(16),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(13),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(17),
+        Constraint::Length(14),
+        Constraint::Length(8),
+    ];
+    
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(if app.real_dollars {
+            "Mortgage Spreadsheet - Today's Dollars"
+        } else {
+            "Mortgage Spreadsheet"
+        }))
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(table, chunks[0], &mut app.table_state);
+
+    let help = Paragraph::new("j/k or ↑/↓: navigate | g/G: top/bottom | s: summary | y: equity by year | r: rent vs buy | p: sell at horizon | m: Monte Carlo | t: sensitivity | a: affordability | o: goal seek | d: today's dollars | e: export CSV | h/←: back | q: quit")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+    f.render_widget(help, chunks[1]);
+}
+
+fn render_rate_type_screen(f: &mut Frame, app: &mut App) {
+    let fixed_option = if !app.inputs.use_arm {
+        "▶ Fixed Rate"
+    } else {
+        "  Fixed Rate"
+    };
+
+    let arm_option = if app.inputs.use_arm {
+        "▶ Adjustable Rate (ARM) - e.g. 5/1, 7/6, 10/6"
+    } else {
+        "  Adjustable Rate (ARM) - e.g. 5/1, 7/6, 10/6"
+    };
+
+    let options_text = vec![
+        Line::from(fixed_option).style(if !app.inputs.use_arm {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }),
+        Line::from(arm_option).style(if app.inputs.use_arm {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }),
+    ];
+
+    let selected = app.inputs.use_arm.then_some(app.form_field);
+    render_form_screen(
+        f,
+        "Rate Type - Press Tab to switch between fixed and adjustable",
+        options_text,
+        &ARM_FIELDS,
+        &app.inputs.arm_values(),
+        selected,
+        "Tab: toggle fixed/ARM | ↑/↓: select field | Enter/→: continue | Esc/←: back",
+    );
+}
+
+fn render_closing_costs_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Closing Costs, Points and Credits (points are % of the loan)",
+        Vec::new(),
+        &CLOSING_FIELDS,
+        &app.inputs.closing_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: continue | Esc/h/←: back",
+    );
+}
+
+/// Renders a multi-field input screen. `header` lines appear above the fields, and the
+/// fields are dimmed when `selected` is `None`.
+fn render_form_screen(
+    f: &mut Frame,
+    title: &str,
+    header: Vec<Line>,
+    fields: &[FormField],
+    values: &[&mut String],
+    selected: Option<usize>,
+    help: &str,
+) {
+    let spacer = usize::from(!header.is_empty() && !fields.is_empty());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length((header.len() + spacer + fields.len() + 2) as u16),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title_widget = Paragraph::new("Home Buyer Calculator")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title_widget, chunks[0]);
+
+    let mut text = header;
+    if spacer > 0 {
+        text.push(Line::from(""));
+    }
+    for (i, (field, value)) in fields.iter().zip(values).enumerate() {
+        let value = match field.kind {
+            FieldKind::Percent => format!("{}%", value),
+            FieldKind::Dollars => format!("${}", value),
+            FieldKind::Points => format!("{} points", value),
+            FieldKind::Years => format!("{} years", value),
+            FieldKind::Months => format!("{} months", value),
+            FieldKind::Month => format!("month {}", value),
+            FieldKind::Number | FieldKind::Text => value.to_string(),
+        };
+        let (marker, style) = match selected {
+            Some(selected) if selected == i => (
+                "▶",
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+            Some(_) => (" ", Style::default()),
+            None => (" ", Style::default().fg(Color::DarkGray)),
+        };
+        text.push(Line::from(format!("{} {}: {}", marker, field.label, value)).style(style));
+    }
+
+    let input = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(input, chunks[1]);
+
+    let help = Paragraph::new(help)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_equity_screen(f: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let Some(schedule) = app.shown_schedule() else {
+        return;
+    };
+
+    let header = Row::new(vec!["Year", "Month", "House Value", "Debt", "Equity", "LTV", "Combined LTV"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .height(1);
+
+    let rows = schedule.summary.anniversaries.iter().map(|snapshot| {
+        Row::new(vec![
+            Cell::from((snapshot.month / 12).to_string()),
+            Cell::from(snapshot.month.to_string()),
+            Cell::from(format!("${:.0}", snapshot.house_value)),
+            Cell::from(format!("${:.0}", snapshot.debt)),
+            Cell::from(format!("${:.0}", snapshot.equity)),
+            Cell::from(format!("{:.1}%", snapshot.ltv * 100.0)),
+            Cell::from(format!("{:.1}%", snapshot.combined_ltv * 100.0)),
+        ])
+        .height(1)
+    });
+
+    let widths = [
+        Constraint::Length(6),
+        Constraint::Length(7),
+        Constraint::Length(13),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(13),
+    ];
+
+    let title = format!(
+        "Equity at Each Anniversary - at payoff (month {}): ${:.0}",
+        schedule.summary.months_to_payoff, schedule.summary.final_equity
+    );
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(table, chunks[0], &mut app.equity_table_state);
+
+    let help = Paragraph::new("j/k or ↑/↓: navigate | g/G: top/bottom | h/←: back to spreadsheet | q: quit")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+    f.render_widget(help, chunks[1]);
+}
+
+fn render_sale_inputs_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Sell at Horizon - when would you sell, and what does selling cost?",
+        Vec::new(),
+        &SALE_FIELDS,
+        &app.inputs.sale_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: analyze | Esc/h/←: back to spreadsheet",
+    );
+}
+
+fn render_goal_seek_inputs_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    let option_line = |selected: bool, label: String| {
+        if selected {
+            Line::from(format!("▶ {}", label)).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        } else {
+            Line::from(format!("  {}", label)).style(Style::default().fg(Color::DarkGray))
+        }
+    };
+    let mut header = vec![Line::from("Output to hit (o):")];
+    for (index, label) in GOAL_OUTPUT_LABELS.iter().enumerate() {
+        header.push(option_line(index == app.inputs.goal_output, label.to_string()));
+    }
+    header.push(Line::from("Input to vary (i):"));
+    for input in GoalInput::ALL {
+        header.push(option_line(input == app.inputs.goal_input, input.to_string()));
+    }
+    render_form_screen(
+        f,
+        "Goal Seek - what input gets you to a target?",
+        header,
+        &GOAL_FIELDS,
+        &app.inputs.goal_values(),
+        selected,
+        "o: output | i: input | ↑/↓: select field | Enter/l/→: solve | Esc/h/←: back to spreadsheet",
+    );
+}
+
+fn render_goal_seek_result_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Goal Seek")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    let input = app.inputs.goal_input;
+    let text = match &app.goal_seek {
+        Some(Ok(found)) => {
+            let value = match input {
+                GoalInput::InterestRate => format!("{:.3}%", found.input_value * 100.0),
+                _ => format!("${:.2}", found.input_value),
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled(format!("Required {}: ", input), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::styled(value, Style::default().fg(Color::Green)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Output Reached: ", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!("{:.2}", found.achieved)),
+                ]),
+            ]
+        }
+        Some(Err(error)) => vec![
+            Line::from(Span::styled("Target is out of reach", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from(error.to_string()),
+        ],
+        None => Vec::new(),
+    };
+
+    let widget = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title("Input Found by Bisection"))
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+    f.render_widget(widget, chunks[1]);
+
+    let help = Paragraph::new("u: use this value | o: edit goal | h/←: back to spreadsheet | q: quit")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_affordability_inputs_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Affordability - what is the most you can pay?",
+        vec![Line::from("Uses your down payment, loan and cost inputs; only the purchase price is solved for.")],
+        &AFFORDABILITY_FIELDS,
+        &app.inputs.affordability_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: solve | Esc/h/←: back to spreadsheet",
+    );
+}
+
+fn render_affordability_result_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Maximum Purchase Price")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    if let Some(affordability) = &app.affordability {
+        let text = vec![
+            Line::from(vec![
+                Span::styled("Maximum Price: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", affordability.max_price), Style::default().fg(Color::Green)),
+            ]),
+            Line::from(vec![
+                Span::styled("Binding Constraint: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(affordability.binding.to_string(), Style::default().fg(Color::Yellow)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Loan Amount: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("${:.0}", affordability.loan_amount)),
+            ]),
+            Line::from(vec![
+                Span::styled("Housing Payment (PITI, HOA, PMI): ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("${:.0}", affordability.housing_payment)),
+            ]),
+            Line::from(vec![
+                Span::styled("Front-End DTI: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{:.1}%", affordability.front_end_ratio * 100.0)),
+            ]),
+            Line::from(vec![
+                Span::styled("Back-End DTI: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{:.1}%", affordability.back_end_ratio * 100.0)),
+            ]),
+            Line::from(vec![
+                Span::styled("Cash to Close: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("${:.0}", affordability.cash_to_close)),
+            ]),
+        ];
+
+        let widget = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title("Highest Price Within Your DTI Limits and Cash"))
+            .alignment(Alignment::Left);
+        f.render_widget(widget, chunks[1]);
+    }
+
+    let help = Paragraph::new("u: use this price | a: edit inputs | h/←: back to spreadsheet | q: quit")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_sensitivity_inputs_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Sensitivity - which input matters most?",
+        vec![Line::from("Each input is lowered and raised by this share of its value, one at a time.")],
+        &SENSITIVITY_FIELDS,
+        &[&mut app.inputs.sensitivity_change],
+        selected,
+        "Enter/l/→: analyze | Esc/h/←: back to spreadsheet",
+    );
+}
+
+fn render_tornado_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Sensitivity Tornado")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    if let Some(analysis) = &app.sensitivity {
+        let metric = app.sensitivity_metric;
+        let metric_name = match metric {
+            SensitivityMetric::TotalWasteCost => "Total After-Tax Waste Cost",
+            SensitivityMetric::MonthlyPayment => "First Monthly Payment",
+            SensitivityMetric::FinalEquity => "Final Equity",
+        };
+        let base = analysis.base.metric(metric);
+        let ranked = analysis.ranked(metric);
+        let widest = ranked.first().map_or(0.0, |input| {
+            (input.low.metric(metric) - base).abs().max((input.high.metric(metric) - base).abs())
+        });
+
+        // Each side of the axis is as wide as the largest move, so bars compare across rows
+        const HALF_WIDTH: usize = 30;
+        let bar = |delta: f64| {
+            if widest > 0.0 {
+                ((delta.abs() / widest) * HALF_WIDTH as f64).round() as usize
+            } else {
+                0
+            }
+        };
+
+        let mut text = vec![
+            Line::from(vec![
+                Span::styled(format!("{}: ", metric_name), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("${:.0} with your inputs", base)),
+            ]),
+            Line::from(vec![
+                Span::styled("■ ", Style::default().fg(Color::Blue)),
+                Span::raw(format!("input -{:.0}%   ", analysis.change * 100.0)),
+                Span::styled("■ ", Style::default().fg(Color::Magenta)),
+                Span::raw(format!("input +{:.0}%", analysis.change * 100.0)),
+            ]),
+            Line::from(""),
+        ];
+        for input in ranked {
+            let low = input.low.metric(metric) - base;
+            let high = input.high.metric(metric) - base;
+            // Draw the larger move on each side of the axis
+            let side = |pick: fn(f64) -> bool| {
+                [(low, Color::Blue), (high, Color::Magenta)]
+                    .into_iter()
+                    .filter(|&(delta, _)| pick(delta))
+                    .max_by(|a, b| a.0.abs().total_cmp(&b.0.abs()))
+                    .map_or((0, Color::Reset), |(delta, color)| (bar(delta), color))
+            };
+            let (left, left_color) = side(|delta| delta < 0.0);
+            let (right, right_color) = side(|delta| delta > 0.0);
+            text.push(Line::from(vec![
+                Span::raw(format!("{:<20}", input.input)),
+                Span::raw(" ".repeat(HALF_WIDTH - left)),
+                Span::styled("█".repeat(left), Style::default().fg(left_color)),
+                Span::raw("│"),
+                Span::styled("█".repeat(right), Style::default().fg(right_color)),
+                Span::raw(" ".repeat(HALF_WIDTH - right)),
+                Span::raw(format!(" {:+.0} / {:+.0}", low, high)),
+            ]));
+        }
+
+        let chart = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(format!("Change in {} - Ranked by Swing", metric_name)));
+        f.render_widget(chart, chunks[1]);
+    }
 
-    let help = Paragraph::new("Tab: toggle between % and $ | Enter/l/→: continue | Esc/h/←: back")
+    let help = Paragraph::new("Tab: next metric | t: change amount | h/←: back to spreadsheet | q: quit")
         .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
     f.render_widget(help, chunks[2]);
 }
 
-fn render_hoa_screen(f: &mut Frame, app: &App) {
+fn render_monte_carlo_inputs_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    let header = vec![Line::from(
+        "Rates are annual. Net worth is measured against renting once the rent comparison (r) is filled in.",
+    )];
+    render_form_screen(
+        f,
+        "Monte Carlo - how uncertain are appreciation, returns and the ARM index?",
+        header,
+        &MONTE_CARLO_FIELDS,
+        &app.inputs.monte_carlo_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: simulate | Esc/h/←: back to spreadsheet",
+    );
+}
+
+fn render_monte_carlo_result_screen(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints(
             [
                 Constraint::Length(3),
+                Constraint::Min(0),
                 Constraint::Length(3),
-                Constraint::Min(1),
             ]
             .as_ref(),
         )
         .split(f.size());
 
-    let title = Paragraph::new("Home Buyer Calculator")
+    let title = Paragraph::new("Monte Carlo Simulation")
         .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::BOTTOM));
     f.render_widget(title, chunks[0]);
 
-    let input_block = Block::default()
-        .borders(Borders::ALL)
-        .title("What is the monthly HOA fee? (0 if none)");
-    
-    let input = Paragraph::new(format!("${}", app.inputs.hoa_fee))
-        .style(Style::default().fg(Color::Yellow))
-        .block(input_block);
-    f.render_widget(input, chunks[1]);
+    if let Some(monte_carlo) = &app.monte_carlo {
+        let header = Row::new(["", "P10", "P50", "P90"].map(Cell::from))
+            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        let band = |label: &str, percentiles: &Percentiles| {
+            Row::new(vec![
+                Cell::from(label.to_string()),
+                Cell::from(format!("${:.0}", percentiles.p10)),
+                Cell::from(format!("${:.0}", percentiles.p50)),
+                Cell::from(format!("${:.0}", percentiles.p90)),
+            ])
+        };
+        let mut rows = vec![
+            band("Final Equity", &monte_carlo.final_equity),
+            band("Total After-Tax Waste Cost", &monte_carlo.waste_cost),
+            band("Buyer Net Worth", &monte_carlo.net_worth),
+        ];
+        if let Some(renter) = &monte_carlo.renter_net_worth {
+            rows.push(band("Renter Net Worth", renter));
+        }
 
-    let help = Paragraph::new("Enter/l/→: continue | Esc/h/←: back")
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(28),
+                Constraint::Length(16),
+                Constraint::Length(16),
+                Constraint::Length(16),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Percentiles at the End of the Term across {} Paths", monte_carlo.paths)),
+        );
+        f.render_widget(table, chunks[1]);
+    }
+
+    let help = Paragraph::new("m: edit assumptions | h/←: back to spreadsheet | q: quit")
         .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
     f.render_widget(help, chunks[2]);
 }
 
-fn render_interest_rate_screen(f: &mut Frame, app: &App) {
+fn render_sale_result_screen(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints(
             [
                 Constraint::Length(3),
+                Constraint::Min(0),
                 Constraint::Length(3),
-                Constraint::Min(1),
             ]
             .as_ref(),
         )
         .split(f.size());
 
-    let title = Paragraph::new("Home Buyer Calculator")
+    let title = Paragraph::new("Sale at Horizon")
         .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::BOTTOM));
     f.render_widget(title, chunks[0]);
 
-    let input_block = Block::default()
-        .borders(Borders::ALL)
-        .title("What is your expected interest rate? (%)");
-    
-    let input = Paragraph::new(format!("{}%", app.inputs.interest_rate))
-        .style(Style::default().fg(Color::Yellow))
-        .block(input_block);
-    f.render_widget(input, chunks[1]);
+    if let Some(sale) = &app.sale {
+        let text = vec![
+            Line::from(vec![
+                Span::styled("Sold in Month: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{} ({:.1} years)", sale.month, sale.month as f64 / 12.0)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Sale Price: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", sale.sale_price), Style::default().fg(Color::Cyan)),
+            ]),
+            Line::from(vec![
+                Span::styled("Realtor Commission: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", sale.commission), Style::default().fg(Color::Red)),
+            ]),
+            Line::from(vec![
+                Span::styled("Transfer Taxes: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", sale.transfer_taxes), Style::default().fg(Color::Red)),
+            ]),
+            Line::from(vec![
+                Span::styled("Other Selling Costs: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", sale.other_costs), Style::default().fg(Color::Red)),
+            ]),
+            Line::from(vec![
+                Span::styled("Remaining Debt: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", sale.remaining_debt), Style::default().fg(Color::Red)),
+            ]),
+            Line::from(vec![
+                Span::styled("Net Sale Proceeds: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", sale.net_proceeds), Style::default().fg(Color::Green)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Total Cash Invested: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("${:.0}", sale.total_cash_invested)),
+            ]),
+            Line::from(vec![
+                Span::styled("Total Return: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!("${:.0}", sale.total_return),
+                    Style::default().fg(if sale.total_return >= 0.0 { Color::Green } else { Color::Red }),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Internal Rate of Return: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(match sale.irr {
+                    Some(irr) => format!("{:.2}% per year", irr * 100.0),
+                    None => "n/a".to_string(),
+                }),
+            ]),
+        ];
 
-    let help = Paragraph::new("Enter/l/→: calculate | Esc/h/←: back")
+        let sale_widget = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title("Net Proceeds and Return on All Cash Put In"))
+            .alignment(Alignment::Left);
+        
+        f.render_widget(sale_widget, chunks[1]);
+    }
+
+    let help = Paragraph::new("p: edit sale | h/←: back to spreadsheet | q: quit")
         .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::TOP));
     f.render_widget(help, chunks[2]);
 }
 
-fn render_spreadsheet_screen(f: &mut Frame, app: &mut App) {
+fn render_rent_inputs_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Rent vs Buy - what would it cost to rent a comparable home?",
+        Vec::new(),
+        &RENT_FIELDS,
+        &app.inputs.rent_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: compare | Esc/h/←: back to spreadsheet",
+    );
+}
+
+fn render_rent_comparison_screen(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
@@ -1045,35 +3212,34 @@ fn render_spreadsheet_screen(f: &mut Frame, app: &mut App) {
         )
         .split(f.size());
 
+    let Some(comparison) = &app.rent_comparison else {
+        return;
+    };
+
     let header_cells = vec![
-        "Month", "Interest", "Principal", "Extra Principal", "Repair Costs", 
-        "HOA", "Taxes", "Insurance", "PMI", "Actual Payment", 
-        "Cost of Capital", "Waste Cost", "Cost", "Debt", 
-        "Interest Rate", "House Cost", "Equity"
+        "Month", "Rent", "Rent Cost", "Buy Waste Cost", "Total Rent Cost",
+        "Total Buy Waste", "Renter Net Worth", "Buyer Net Worth", "Buy Advantage",
     ];
     let header = Row::new(header_cells)
         .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
         .height(1);
 
-    let rows = app.spreadsheet_data.iter().map(|row| {
+    let rows = comparison.rows.iter().map(|row| {
+        let advantage = row.buyer_net_worth - row.renter_net_worth;
         let cells = vec![
             Cell::from(row.month.to_string()),
-            Cell::from(format!("${:.0}", row.interest)),
-            Cell::from(format!("${:.0}", row.principal)),
-            Cell::from(format!("${:.0}", row.extra_principal)),
-            Cell::from(format!("${:.0}", row.repair_costs)),
-            Cell::from(format!("${:.0}", row.hoa)),
-            Cell::from(format!("${:.0}", row.taxes)),
-            Cell::from(format!("${:.0}", row.insurance)),
-            Cell::from(format!("${:.0}", row.pmi)),
-            Cell::from(format!("${:.0}", row.actual_payment)),
-            Cell::from(format!("${:.0}", row.cost_of_capital)),
-            Cell::from(format!("${:.0}", row.waste_cost)),
-            Cell::from(format!("${:.0}", row.cost)),
-            Cell::from(format!("${:.0}", row.debt)),
-            Cell::from(format!("{:.2}%", row.interest_rate * 100.0)),
-            Cell::from(format!("${:.0}", row.house_cost)),
-            Cell::from(format!("${:.0}", row.equity)),
+            Cell::from(format!("${:.0}", row.rent)),
+            Cell::from(format!("${:.0}", row.rent_cost)),
+            Cell::from(format!("${:.0}", row.buy_waste_cost)),
+            Cell::from(format!("${:.0}", row.cumulative_rent_cost)),
+            Cell::from(format!("${:.0}", row.cumulative_buy_waste_cost)),
+            Cell::from(format!("${:.0}", row.renter_net_worth)),
+            Cell::from(format!("${:.0}", row.buyer_net_worth)),
+            Cell::from(format!("${:.0}", advantage)).style(Style::default().fg(if advantage >= 0.0 {
+                Color::Green
+            } else {
+                Color::Red
+            })),
         ];
         Row::new(cells).height(1)
     });
@@ -1083,30 +3249,31 @@ fn render_spreadsheet_screen(f: &mut Frame, app: &mut App) {
         Constraint::Length(10),
         Constraint::Length(10),
         Constraint::Length(15),
-        Constraint::Length(12),
-        Constraint::Length(8),
-        Constraint::Length(10),
-        Constraint::Length(10),
-        Constraint::Length(8),
+        Constraint::Length(16),
+        Constraint::Length(16),
+        Constraint::Length(17),
+        Constraint::Length(16),
         Constraint::Length(14),
-        Constraint::Length(15),
-        Constraint::Length(11),
-        Constraint::Length(10),
-        Constraint::Length(12),
-        Constraint::Length(13),
-        Constraint::Length(12),
-        Constraint::Length(12),
     ];
-    
+
+    let title = match comparison.break_even_month {
+        Some(month) => format!(
+            "Rent vs Buy - buying pulls ahead for good in month {} ({:.1} years)",
+            month,
+            month as f64 / 12.0
+        ),
+        None => "Rent vs Buy - buying never pulls ahead of renting".to_string(),
+    };
+
     let table = Table::new(rows, widths)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Mortgage Spreadsheet"))
+        .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().bg(Color::DarkGray))
         .highlight_symbol(">> ");
 
-    f.render_stateful_widget(table, chunks[0], &mut app.table_state);
+    f.render_stateful_widget(table, chunks[0], &mut app.rent_table_state);
 
-    let help = Paragraph::new("j/k or ↑/↓: navigate | g/G: top/bottom | s: summary | e: export CSV | h/←: back | q: quit")
+    let help = Paragraph::new("j/k or ↑/↓: navigate | g/G: top/bottom | r: edit rent | h/←: back to spreadsheet | q: quit")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::TOP));
@@ -1175,6 +3342,25 @@ fn render_property_tax_screen(f: &mut Frame, app: &App) {
     f.render_widget(help, chunks[2]);
 }
 
+fn render_assessment_screen(f: &mut Frame, app: &mut App) {
+    let header = if app.inputs.use_property_tax_percent {
+        vec![Line::from("Caps, cycles and freezes start from the purchase price and change on anniversaries")
+            .style(Style::default().fg(Color::DarkGray))]
+    } else {
+        vec![Line::from("(Not used: property tax is a fixed annual amount)").style(Style::default().fg(Color::Cyan))]
+    };
+    let selected = app.inputs.use_property_tax_percent.then_some(app.form_field);
+    render_form_screen(
+        f,
+        "Property Tax Assessment",
+        header,
+        &ASSESSMENT_FIELDS,
+        &app.inputs.assessment_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: continue | Esc/h/←: back",
+    );
+}
+
 fn render_insurance_screen(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -1306,7 +3492,7 @@ fn render_pmi_screen(f: &mut Frame, app: &App) {
         .constraints(
             [
                 Constraint::Length(3),
-                Constraint::Length(8),
+                Constraint::Length(12),
                 Constraint::Min(1),
             ]
             .as_ref(),
@@ -1334,7 +3520,9 @@ fn render_pmi_screen(f: &mut Frame, app: &App) {
         format!("  Fixed Monthly Amount: {}", amount_value)
     };
 
-    let down_payment_note = if let Ok(house_value) = app.inputs.house_value.parse::<f64>() {
+    let down_payment_note = if app.inputs.loan_program != LoanProgram::Conventional {
+        "(Not used: the loan program sets its own mortgage insurance)"
+    } else if let Ok(house_value) = app.inputs.house_value.parse::<f64>() {
         let down_payment_result = if app.inputs.use_percent {
             app.inputs.down_payment_percent.parse::<f64>().map(|p| p / 100.0)
         } else {
@@ -1372,6 +3560,18 @@ fn render_pmi_screen(f: &mut Frame, app: &App) {
         options_text.push(Line::from(down_payment_note).style(Style::default().fg(Color::Cyan)));
     }
 
+    let checkbox = |checked: bool| if checked { "[x]" } else { "[ ]" };
+    options_text.push(Line::from(""));
+    options_text.push(Line::from(format!(
+        "{} r: Request cancellation at 80% LTV of original value",
+        checkbox(app.inputs.pmi_borrower_request)
+    )));
+    options_text.push(Line::from(format!(
+        "{} a: Cancel on a new appraisal (75% LTV after 2 years, 80% after 5)",
+        checkbox(app.inputs.pmi_new_appraisal)
+    )));
+    options_text.push(Line::from("Always ends at 78% of original value or the term midpoint").style(Style::default().fg(Color::DarkGray)));
+
     let input_block = Block::default()
         .borders(Borders::ALL)
         .title("PMI - Private Mortgage Insurance - Press Tab to switch between options");
@@ -1380,7 +3580,7 @@ fn render_pmi_screen(f: &mut Frame, app: &App) {
         .block(input_block);
     f.render_widget(input, chunks[1]);
 
-    let help = Paragraph::new("Tab: toggle between % and $ | Enter/l/→: continue | Esc/h/←: back")
+    let help = Paragraph::new("Tab: toggle between % and $ | r/a: toggle cancellation | Enter/l/→: continue | Esc/h/←: back")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
     f.render_widget(help, chunks[2]);
@@ -1420,6 +3620,65 @@ fn render_house_appreciation_screen(f: &mut Frame, app: &App) {
         .alignment(Alignment::Center);
     f.render_widget(help, chunks[2]);
 }
+
+fn render_inflation_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Inflation - yearly increases to HOA and fixed-dollar costs",
+        Vec::new(),
+        &INFLATION_FIELDS,
+        &app.inputs.inflation_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: continue | Esc/h/←: back",
+    );
+}
+
+fn render_investment_return_screen(f: &mut Frame, app: &mut App) {
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Opportunity Cost - what would your equity earn invested elsewhere?",
+        Vec::new(),
+        &INVESTMENT_FIELDS,
+        &app.inputs.investment_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: continue | Esc/h/←: back",
+    );
+}
+
+fn render_income_tax_screen(f: &mut Frame, app: &mut App) {
+    let filing_status = app.inputs.filing_status;
+    let options = [
+        (None, "Not modeled - interest and property tax are not deductible"),
+        (Some(FilingStatus::Single), "Single"),
+        (Some(FilingStatus::MarriedFilingJointly), "Married Filing Jointly"),
+        (Some(FilingStatus::MarriedFilingSeparately), "Married Filing Separately"),
+        (Some(FilingStatus::HeadOfHousehold), "Head of Household"),
+    ];
+    let options_text = options
+        .iter()
+        .map(|&(status, label)| {
+            if status == filing_status {
+                Line::from(format!("▶ {}", label)).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            } else {
+                Line::from(format!("  {}", label)).style(Style::default().fg(Color::DarkGray))
+            }
+        })
+        .collect();
+
+    let selected = filing_status.is_some().then_some(app.form_field);
+    render_form_screen(
+        f,
+        "Federal Income Tax - Press Tab to switch filing status",
+        options_text,
+        &TAX_FIELDS,
+        &app.inputs.tax_values(),
+        selected,
+        "Tab: filing status | ↑/↓: select field | Enter/→: continue | Esc/←: back",
+    );
+}
+
 fn render_loan_term_screen(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -1427,7 +3686,7 @@ fn render_loan_term_screen(f: &mut Frame, app: &App) {
         .constraints(
             [
                 Constraint::Length(3),
-                Constraint::Length(3),
+                Constraint::Length(7),
                 Constraint::Min(1),
             ]
             .as_ref(),
@@ -1440,29 +3699,231 @@ fn render_loan_term_screen(f: &mut Frame, app: &App) {
         .block(Block::default().borders(Borders::BOTTOM));
     f.render_widget(title, chunks[0]);
 
+    let years_value = format!("{} years", app.inputs.loan_term_years);
+    let months_value = format!("{} months", app.inputs.loan_term_months);
+    
+    let years_option = if app.inputs.use_loan_term_years {
+        format!("▶ Years: {}", years_value)
+    } else {
+        format!("  Years: {}", years_value)
+    };
+    
+    let months_option = if !app.inputs.use_loan_term_years {
+        format!("▶ Months: {}", months_value)
+    } else {
+        format!("  Months: {}", months_value)
+    };
+
+    let options_text = vec![
+        Line::from(years_option).style(if app.inputs.use_loan_term_years { 
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) 
+        } else { 
+            Style::default().fg(Color::DarkGray) 
+        }),
+        Line::from(months_option).style(if !app.inputs.use_loan_term_years { 
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) 
+        } else { 
+            Style::default().fg(Color::DarkGray) 
+        }),
+    ];
+
     let input_block = Block::default()
         .borders(Borders::ALL)
-        .title("Loan Term (years) - common values: 15, 20, 30");
+        .title("Loan Term - common values: 15, 20, 30 years - Press Tab to switch between options");
     
-    let input = Paragraph::new(format!("{} years", app.inputs.loan_term_years))
-        .style(Style::default().fg(Color::Yellow))
+    let input = Paragraph::new(options_text)
         .block(input_block);
     f.render_widget(input, chunks[1]);
 
-    let help = Paragraph::new("Enter/l/→: continue | Esc/h/←: back")
+    let help = Paragraph::new("Tab: toggle between years and months | Enter/l/→: continue | Esc/h/←: back")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
     f.render_widget(help, chunks[2]);
 }
 
-fn render_extra_principal_screen(f: &mut Frame, app: &App) {
+fn render_loan_structure_screen(f: &mut Frame, app: &mut App) {
+    let header = vec![Line::from(
+        "Interest-only payments come first; a balloon loan amortizes over the term but the balance falls due early",
+    )
+    .style(Style::default().fg(Color::DarkGray))];
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Loan Structure",
+        header,
+        &LOAN_STRUCTURE_FIELDS,
+        &app.inputs.loan_structure_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: continue | Esc/h/←: back",
+    );
+}
+
+fn render_second_lien_screen(f: &mut Frame, app: &mut App) {
+    let lien_type = app.inputs.second_lien;
+    let options = [
+        (None, "None - the first mortgage finances everything past the down payment"),
+        (Some(SecondLienType::Fixed), "Fixed-rate second mortgage (e.g. the 10 in an 80/10/10)"),
+        (Some(SecondLienType::Heloc), "HELOC - variable rate, interest-only draw period then repayment"),
+    ];
+    let options_text = options
+        .iter()
+        .map(|&(option, label)| {
+            if option == lien_type {
+                Line::from(format!("▶ {}", label)).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            } else {
+                Line::from(format!("  {}", label)).style(Style::default().fg(Color::DarkGray))
+            }
+        })
+        .collect();
+
+    let selected = lien_type.is_some().then_some(app.form_field);
+    let title = "Second Lien - Press Tab to switch type";
+    let help = "Tab: second lien type | ↑/↓: select field | Enter/→: continue | Esc/←: back";
+    match lien_type {
+        None => render_form_screen(f, title, options_text, &[], &[], selected, help),
+        Some(SecondLienType::Fixed) => render_form_screen(
+            f,
+            title,
+            options_text,
+            &FIXED_SECOND_FIELDS,
+            &app.inputs.fixed_second_values(),
+            selected,
+            help,
+        ),
+        Some(SecondLienType::Heloc) => render_form_screen(
+            f,
+            title,
+            options_text,
+            &HELOC_FIELDS,
+            &app.inputs.heloc_values(),
+            selected,
+            help,
+        ),
+    }
+}
+
+fn render_payment_frequency_screen(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints(
             [
                 Constraint::Length(3),
+                Constraint::Length(7),
+                Constraint::Min(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    let title = Paragraph::new("Home Buyer Calculator")
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(title, chunks[0]);
+
+    let options_text: Vec<Line> = PaymentFrequency::ALL
+        .iter()
+        .map(|&frequency| {
+            let label = match frequency {
+                PaymentFrequency::Monthly => "Monthly - 12 payments a year",
+                PaymentFrequency::SemiMonthly => "Semi-monthly - 24 half payments a year",
+                PaymentFrequency::Biweekly => "Biweekly - 26 payments a year, amortized over the term",
+                PaymentFrequency::AcceleratedBiweekly => "Accelerated biweekly - half the monthly payment every two weeks",
+                PaymentFrequency::Weekly => "Weekly - 52 payments a year, amortized over the term",
+            };
+            if frequency == app.inputs.payment_frequency {
+                Line::from(format!("▶ {}", label)).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            } else {
+                Line::from(format!("  {}", label)).style(Style::default().fg(Color::DarkGray))
+            }
+        })
+        .collect();
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title("Payment Frequency - Press Tab to switch between schedules");
+
+    let input = Paragraph::new(options_text)
+        .block(input_block);
+    f.render_widget(input, chunks[1]);
+
+    let help = Paragraph::new("Tab: next frequency | Enter/l/→: continue | Esc/h/←: back")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_prepayments_screen(f: &mut Frame, app: &mut App) {
+    let options = [
+        (!app.inputs.recast, "Keep the payment - prepayments shorten the term"),
+        (app.inputs.recast, "Recast after each prepayment - the payment drops over the same term"),
+    ];
+    let options_text = options
+        .iter()
+        .map(|&(selected, label)| {
+            if selected {
+                Line::from(format!("▶ {}", label)).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            } else {
+                Line::from(format!("  {}", label)).style(Style::default().fg(Color::DarkGray))
+            }
+        })
+        .collect();
+
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Lump-Sum Prepayments (optional) - Press Tab to switch strategy",
+        options_text,
+        &PREPAYMENT_FIELDS,
+        &app.inputs.prepayment_values(),
+        selected,
+        "Tab: shorten term/recast | ↑/↓: select field | Enter/→: continue | Esc/←: back",
+    );
+}
+
+fn render_rental_income_screen(f: &mut Frame, app: &mut App) {
+    let header = vec![Line::from(
+        "Rent from a duplex unit, ADU or rooms, net of vacancy, management and repairs, offsets your payment",
+    )
+    .style(Style::default().fg(Color::DarkGray))];
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Rental Income (optional)",
+        header,
+        &RENTAL_FIELDS,
+        &app.inputs.rental_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: continue | Esc/h/←: back",
+    );
+}
+
+fn render_refinance_screen(f: &mut Frame, app: &mut App) {
+    let header = vec![Line::from(
+        "A new fixed-rate loan pays off the balance plus any cash out and starts its own term",
+    )
+    .style(Style::default().fg(Color::DarkGray))];
+    let selected = Some(app.form_field);
+    render_form_screen(
+        f,
+        "Refinance (optional)",
+        header,
+        &REFINANCE_FIELDS,
+        &app.inputs.refinance_values(),
+        selected,
+        "↑/↓: select field | Enter/l/→: continue | Esc/h/←: back",
+    );
+}
+
+fn render_extra_principal_screen(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints(
+            [
                 Constraint::Length(3),
+                Constraint::Length(5),
                 Constraint::Min(1),
             ]
             .as_ref(),
@@ -1478,19 +3939,23 @@ fn render_extra_principal_screen(f: &mut Frame, app: &App) {
     let input_block = Block::default()
         .borders(Borders::ALL)
         .title("Extra Monthly Principal Payment (optional)");
-    
-    let input = Paragraph::new(format!("${}", app.inputs.extra_principal_payment))
-        .style(Style::default().fg(Color::Yellow))
-        .block(input_block);
+
+    let checkbox = if app.inputs.rounding == Rounding::HalfEven { "[x]" } else { "[ ]" };
+    let text = vec![
+        Line::from(format!("${}", app.inputs.extra_principal_payment)).style(Style::default().fg(Color::Yellow)),
+        Line::from(""),
+        Line::from(format!("{} b: Banker's rounding (half cents round to even instead of up)", checkbox)),
+    ];
+    let input = Paragraph::new(text).block(input_block);
     f.render_widget(input, chunks[1]);
 
-    let help = Paragraph::new("Enter/l/→: calculate | Esc/h/←: back")
+    let help = Paragraph::new("b: toggle rounding | Enter/l/→: calculate | Esc/h/←: back")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
     f.render_widget(help, chunks[2]);
 }
 
-fn render_summary_screen(f: &mut Frame, app: &App) {
+fn render_summary_screen(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
@@ -1510,8 +3975,8 @@ fn render_summary_screen(f: &mut Frame, app: &App) {
         .block(Block::default().borders(Borders::BOTTOM));
     f.render_widget(title, chunks[0]);
 
-    if let Some(summary) = &app.summary {
-        let text = vec![
+    if let Some(summary) = app.shown_schedule().map(|schedule| &schedule.summary) {
+        let mut text = vec![
             Line::from(vec![
                 Span::styled("Total Payments: ", Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(format!("${:.0}", summary.total_payments)),
@@ -1545,6 +4010,10 @@ fn render_summary_screen(f: &mut Frame, app: &App) {
                 Span::styled("HOA Fees: ", Style::default().add_modifier(Modifier::BOLD)),
                 Span::styled(format!("${:.0}", summary.total_hoa_paid), Style::default().fg(Color::Yellow)),
             ]),
+            Line::from(vec![
+                Span::styled("Net Rental Income: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", summary.total_rental_income), Style::default().fg(Color::Green)),
+            ]),
             Line::from(""),
             Line::from(vec![
                 Span::styled("Cost of Capital: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -1554,6 +4023,14 @@ fn render_summary_screen(f: &mut Frame, app: &App) {
                 Span::styled("Total Waste Cost: ", Style::default().add_modifier(Modifier::BOLD)),
                 Span::styled(format!("${:.0}", summary.total_waste_cost), Style::default().fg(Color::Red)),
             ]),
+            Line::from(vec![
+                Span::styled("Tax Benefit: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", summary.total_tax_benefit), Style::default().fg(Color::Green)),
+            ]),
+            Line::from(vec![
+                Span::styled("After-Tax Waste Cost: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", summary.total_after_tax_waste_cost), Style::default().fg(Color::Red)),
+            ]),
             Line::from(""),
             Line::from(vec![
                 Span::styled("Final House Value: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -1574,14 +4051,179 @@ fn render_summary_screen(f: &mut Frame, app: &App) {
             ]),
         ];
 
+        text.push(Line::from(""));
+        text.push(Line::from(vec![
+            Span::styled("Closing Costs (net): ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("${:.0}", summary.closing_costs), Style::default().fg(Color::Yellow)),
+        ]));
+        text.push(Line::from(vec![
+            Span::styled("Cash to Close: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!("${:.0}", summary.cash_to_close)),
+        ]));
+        if let Some(month) = summary.points_break_even_month {
+            text.push(Line::from(vec![
+                Span::styled("Points Break-Even: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("month {} ({:.1} years)", month, month as f64 / 12.0)),
+            ]));
+        }
+        if summary.upfront_program_fee > 0.0 {
+            text.push(Line::from(vec![
+                Span::styled("Up-front Program Fee (financed): ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", summary.upfront_program_fee), Style::default().fg(Color::Yellow)),
+            ]));
+        }
+        if let Some(month) = summary.pmi_dropoff_month {
+            text.push(Line::from(vec![
+                Span::styled("PMI Dropped Off: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("month {} ({:.1} years)", month, month as f64 / 12.0)),
+            ]));
+        }
+        // The refinance, prepayment and frequency comparisons are not deflated
+        let nominal_note = || {
+            Line::from(Span::styled("In nominal dollars", Style::default().fg(Color::DarkGray)))
+        };
+        if let Some(refinance) = &app.refinance {
+            let refinance_line = |label: &str, value: String| {
+                Line::from(vec![
+                    Span::styled(format!("{}: ", label), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(value),
+                ])
+            };
+            text.push(Line::from(""));
+            text.push(Line::from(Span::styled(
+                format!("Refinance in Month {}", refinance.month),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )));
+            if app.real_dollars {
+                text.push(nominal_note());
+            }
+            text.push(refinance_line(
+                "New Loan",
+                format!("${:.0} paying off ${:.0}", refinance.new_loan_amount, refinance.payoff_balance),
+            ));
+            text.push(refinance_line(
+                "Payment",
+                format!("${:.2} instead of ${:.2}", refinance.new_payment, refinance.old_payment),
+            ));
+            text.push(refinance_line(
+                "Break-Even",
+                match refinance.break_even_month {
+                    Some(month) => format!(
+                        "month {} ({:.1} years after refinancing)",
+                        month,
+                        (month - refinance.month) as f64 / 12.0
+                    ),
+                    None => "never".to_string(),
+                },
+            ));
+            text.push(refinance_line("Interest Saved", format!("${:.0}", refinance.interest_saved)));
+            text.push(refinance_line("After-Tax Waste Cost Saved", format!("${:.0}", refinance.waste_cost_saved)));
+        }
+        if let Some(comparison) = &app.prepayment_comparison {
+            let outcome_line = |label: &str, outcome: &PrepaymentOutcome| {
+                Line::from(vec![
+                    Span::styled(format!("{}: ", label), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(
+                        "paid off in month {}, ${:.0} interest, ${:.0} payment at the end",
+                        outcome.months_to_payoff, outcome.total_interest_paid, outcome.final_required_payment
+                    )),
+                ])
+            };
+            text.push(Line::from(""));
+            if app.real_dollars {
+                text.push(nominal_note());
+            }
+            text.push(outcome_line("Without Prepayments", &comparison.without_prepayments));
+            text.push(outcome_line("Prepay, Shorten Term", &comparison.shorten_term));
+            text.push(outcome_line("Prepay and Recast, Lower Payment", &comparison.lower_payment));
+        }
+        if let Some(outcomes) = &app.frequency_comparison {
+            text.push(Line::from(""));
+            if app.real_dollars {
+                text.push(nominal_note());
+            }
+            for outcome in outcomes {
+                let style = if outcome.frequency == app.inputs.payment_frequency {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().add_modifier(Modifier::BOLD)
+                };
+                text.push(Line::from(vec![
+                    Span::styled(format!("{}: ", outcome.frequency), style),
+                    Span::raw(format!(
+                        "paid off in month {}, ${:.0} interest, saves {} months and ${:.0} vs monthly",
+                        outcome.months_to_payoff, outcome.total_interest_paid, outcome.months_saved, outcome.interest_saved
+                    )),
+                ]));
+            }
+        }
+        if summary.second_lien_interest_paid > 0.0 {
+            text.push(Line::from(""));
+            text.push(Line::from(vec![
+                Span::styled("First Lien Interest: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("${:.0}", summary.total_interest_paid - summary.second_lien_interest_paid)),
+            ]));
+            text.push(Line::from(vec![
+                Span::styled("Second Lien Interest: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("${:.0}", summary.second_lien_interest_paid)),
+            ]));
+        }
+        if let Some(month) = summary.balloon_month {
+            text.push(Line::from(""));
+            text.push(Line::from(vec![
+                Span::styled(format!("Balloon Due in Month {}: ", month), Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("${:.0}", summary.balloon_payment), Style::default().fg(Color::Red)),
+            ]));
+            // Pay the balloon in cash or refinance it against the home's value at the time
+            if let Some(row) = app.rows().iter().find(|row| row.month == month) {
+                text.push(Line::from(vec![
+                    Span::styled("Refinance Need: ", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(
+                        "${:.0} new loan at {:.1}% LTV on a ${:.0} home",
+                        summary.balloon_payment,
+                        summary.balloon_payment / row.house_cost * 100.0,
+                        row.house_cost
+                    )),
+                ]));
+            }
+        }
+
+        if let Some(present) = &app.present_values {
+            let present_line = |label: &str, value: f64| {
+                Line::from(vec![
+                    Span::styled(format!("{}: ", label), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!("${:.0}", value)),
+                ])
+            };
+            text.push(Line::from(""));
+            text.push(Line::from(Span::styled(
+                format!("Present Values at {:.2}%", present.discount_rate * 100.0),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )));
+            text.push(present_line("PV of Total Payments", present.total_payments));
+            text.push(present_line("PV of Interest", present.total_interest_paid));
+            text.push(present_line("PV of After-Tax Waste Cost", present.total_after_tax_waste_cost));
+            text.push(present_line("PV of Final Equity", present.final_equity));
+        }
+
+        // Keep the last line at the bottom of the box when scrolled all the way down
+        let visible = chunks[1].height.saturating_sub(2) as usize;
+        let max_scroll = text.len().saturating_sub(visible) as u16;
+        app.summary_scroll = app.summary_scroll.min(max_scroll);
+
         let summary_widget = Paragraph::new(text)
-            .block(Block::default().borders(Borders::ALL).title("Financial Summary"))
-            .alignment(Alignment::Left);
+            .block(Block::default().borders(Borders::ALL).title(if app.real_dollars {
+                "Financial Summary - Today's Dollars"
+            } else {
+                "Financial Summary"
+            }))
+            .alignment(Alignment::Left)
+            .scroll((app.summary_scroll, 0));
         
         f.render_widget(summary_widget, chunks[1]);
     }
 
-    let help = Paragraph::new("e: export to CSV | h/←: back to spreadsheet | q: quit")
+    let help = Paragraph::new("j/k: scroll | d: today's dollars | e: export to CSV | h/←: back to spreadsheet | q: quit")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::TOP));
@@ -0,0 +1,33 @@
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+/// How the servicer rounds the payment, interest and principal to whole cents.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Rounding {
+    /// Half a cent rounds up, as most servicers do.
+    #[default]
+    HalfUp,
+    /// Half a cent rounds to the even cent (banker's rounding).
+    HalfEven,
+}
+
+impl Rounding {
+    pub(crate) fn cents(self, amount: Decimal) -> Decimal {
+        let strategy = match self {
+            Rounding::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Rounding::HalfEven => RoundingStrategy::MidpointNearestEven,
+        };
+        amount.round_dp_with_strategy(2, strategy)
+    }
+}
+
+/// Exact decimal for an input entered as `f64`, taking the shortest decimal that
+/// round-trips so `0.065` becomes exactly 6.5%. Non-finite values become zero.
+pub(crate) fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or_default()
+}
+
+pub(crate) fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
@@ -0,0 +1,216 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use serde::{Deserialize, Serialize};
+
+use crate::arm::IndexPath;
+use crate::rent::{compare_rent_schedule, RentScenario};
+use crate::scenario::Scenario;
+use crate::schedule::simulate_path;
+
+/// Annual mean and standard deviation of a simulated rate.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Factor {
+    pub mean: f64,
+    pub volatility: f64,
+}
+
+/// What the simulated paths are drawn from. Each year every path draws a correlated
+/// normal shock for home price growth, the alternative investment return and, for an
+/// ARM, the change in its index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloAssumptions {
+    pub paths: u32,
+    /// Seed for the random number generator, so a run can be reproduced.
+    pub seed: u64,
+    /// Annual home price growth.
+    pub appreciation: Factor,
+    /// Annual pre-tax return on the alternative investment.
+    pub investment_return: Factor,
+    /// Annual change in the ARM index, drifting from purchase onward from the rate the
+    /// scenario's index has at the first reset. Ignored for a fixed-rate loan.
+    pub index_change: Factor,
+    pub appreciation_return_correlation: f64,
+    pub appreciation_index_correlation: f64,
+    pub return_index_correlation: f64,
+    /// Renting alternative for net worth, compared the way [`compare_rent`] does.
+    ///
+    /// [`compare_rent`]: crate::compare_rent
+    pub rent: Option<RentScenario>,
+}
+
+impl MonteCarloAssumptions {
+    /// Centers the paths on the scenario's own appreciation and investment return, with
+    /// typical volatilities and home prices falling somewhat when rates rise.
+    pub fn for_scenario(scenario: &Scenario) -> Self {
+        Self {
+            paths: 1000,
+            seed: 1,
+            appreciation: Factor {
+                mean: scenario.appreciation_rate,
+                volatility: 0.05,
+            },
+            investment_return: Factor {
+                mean: scenario.investment_return,
+                volatility: 0.15,
+            },
+            index_change: Factor {
+                mean: 0.0,
+                volatility: 0.01,
+            },
+            appreciation_return_correlation: 0.2,
+            appreciation_index_correlation: -0.3,
+            return_index_correlation: 0.0,
+            rent: None,
+        }
+    }
+
+    /// Lower-triangular factor of the correlation matrix, or `None` when the three
+    /// correlations cannot hold together.
+    fn cholesky(&self) -> Option<[[f64; 3]; 3]> {
+        let a = self.appreciation_return_correlation;
+        let b = self.appreciation_index_correlation;
+        let c = self.return_index_correlation;
+        if [a, b, c].iter().any(|rho| !(-1.0..=1.0).contains(rho)) {
+            return None;
+        }
+        let l22 = (1.0 - a * a).sqrt();
+        let l32 = if l22 > 0.0 { (c - a * b) / l22 } else { 0.0 };
+        let l33_squared = 1.0 - b * b - l32 * l32;
+        if l33_squared < -1e-12 {
+            return None;
+        }
+        Some([[1.0, 0.0, 0.0], [a, l22, 0.0], [b, l32, l33_squared.max(0.0).sqrt()]])
+    }
+}
+
+/// One simulated path of annual rates, indexed by year of ownership.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MarketPath {
+    appreciation: Vec<f64>,
+    investment_return: Vec<f64>,
+}
+
+impl MarketPath {
+    /// Appreciation and pre-tax investment return in force during `year`.
+    pub(crate) fn rates(&self, year: u32) -> (f64, f64) {
+        let year = year as usize;
+        (
+            self.appreciation[year.min(self.appreciation.len() - 1)],
+            self.investment_return[year.min(self.investment_return.len() - 1)],
+        )
+    }
+}
+
+/// 10th, 50th and 90th percentiles across the simulated paths.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Percentiles {
+    pub p10: f64,
+    pub p50: f64,
+    pub p90: f64,
+}
+
+impl Percentiles {
+    fn of(mut values: Vec<f64>) -> Self {
+        values.sort_by(f64::total_cmp);
+        let at = |percentile: f64| {
+            let rank = percentile * (values.len() - 1) as f64;
+            let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+            values[low] + (values[high] - values[low]) * (rank - low as f64)
+        };
+        Self {
+            p10: at(0.10),
+            p50: at(0.50),
+            p90: at(0.90),
+        }
+    }
+}
+
+/// Percentile bands at the end of the loan term.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarlo {
+    pub paths: u32,
+    pub final_equity: Percentiles,
+    /// Total waste cost after any income tax benefit.
+    pub waste_cost: Percentiles,
+    /// Buyer's final equity plus any savings invested in months where owning was cheaper
+    /// than renting; just the final equity without a renting alternative.
+    pub net_worth: Percentiles,
+    /// Renter's final net worth, when there is a renting alternative.
+    pub renter_net_worth: Option<Percentiles>,
+}
+
+/// Runs `scenario` along `assumptions.paths` simulated paths. Returns `None` when there
+/// are no paths or the correlations are not a valid correlation matrix. With no loan to
+/// schedule, net worth is the home's equity against the renter's starting investment.
+pub fn run_monte_carlo(scenario: &Scenario, assumptions: &MonteCarloAssumptions) -> Option<MonteCarlo> {
+    let cholesky = assumptions.cholesky()?;
+    if assumptions.paths == 0 {
+        return None;
+    }
+    let years = scenario.loan_term.months().div_ceil(12).max(1) as usize;
+    let mut rng = StdRng::seed_from_u64(assumptions.seed);
+
+    let mut final_equity = Vec::with_capacity(assumptions.paths as usize);
+    let mut waste_cost = Vec::with_capacity(assumptions.paths as usize);
+    let mut net_worth = Vec::with_capacity(assumptions.paths as usize);
+    let mut renter_net_worth = Vec::with_capacity(assumptions.paths as usize);
+
+    for _ in 0..assumptions.paths {
+        let mut path = MarketPath {
+            appreciation: Vec::with_capacity(years),
+            investment_return: Vec::with_capacity(years),
+        };
+        let mut index_steps = Vec::with_capacity(years);
+        let mut index = scenario
+            .arm
+            .as_ref()
+            .map_or(0.0, |arm| arm.index.rate_at(arm.initial_period_months + 1));
+
+        for year in 0..years {
+            let draws: [f64; 3] = [rng.sample(StandardNormal), rng.sample(StandardNormal), rng.sample(StandardNormal)];
+            let shock = |row: usize| (0..3).map(|column| cholesky[row][column] * draws[column]).sum::<f64>();
+            path.appreciation.push(assumptions.appreciation.mean + assumptions.appreciation.volatility * shock(0));
+            path.investment_return
+                .push(assumptions.investment_return.mean + assumptions.investment_return.volatility * shock(1));
+            index = (index + assumptions.index_change.mean + assumptions.index_change.volatility * shock(2)).max(0.0);
+            index_steps.push((year as u32 * 12 + 1, index));
+        }
+
+        let mut path_scenario = scenario.clone();
+        if let Some(arm) = &mut path_scenario.arm {
+            arm.index = IndexPath::Stepped(index_steps);
+        }
+        let schedule = simulate_path(&path_scenario, Some(&path));
+
+        final_equity.push(schedule.summary.final_equity);
+        waste_cost.push(schedule.summary.total_after_tax_waste_cost);
+        match &assumptions.rent {
+            Some(rent) => {
+                let comparison = compare_rent_schedule(scenario, &schedule, rent, |month| {
+                    let (_, investment_return) = path.rates((month - 1) / 12);
+                    investment_return * (1.0 - scenario.investment_tax_rate) / 12.0
+                });
+                match comparison.rows.last() {
+                    Some(last) => {
+                        net_worth.push(last.buyer_net_worth);
+                        renter_net_worth.push(last.renter_net_worth);
+                    }
+                    None => {
+                        net_worth.push(schedule.summary.final_equity);
+                        renter_net_worth.push(scenario.cash_to_close());
+                    }
+                }
+            }
+            None => net_worth.push(schedule.summary.final_equity),
+        }
+    }
+
+    Some(MonteCarlo {
+        paths: assumptions.paths,
+        final_equity: Percentiles::of(final_equity),
+        waste_cost: Percentiles::of(waste_cost),
+        net_worth: Percentiles::of(net_worth),
+        renter_net_worth: assumptions.rent.is_some().then(|| Percentiles::of(renter_net_worth)),
+    })
+}
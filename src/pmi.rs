@@ -0,0 +1,84 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Which PMI cancellation routes the borrower will pursue. Automatic termination at 78%
+/// of original value and at the midpoint of the term always applies.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PmiCancellation {
+    /// Request cancellation once the balance reaches 80% of the original value.
+    pub borrower_request: bool,
+    /// Order a new appraisal and cancel on appreciated value: 75% LTV after two years
+    /// of seasoning, 80% after five.
+    pub new_appraisal: bool,
+}
+
+impl Default for PmiCancellation {
+    fn default() -> Self {
+        Self {
+            borrower_request: true,
+            new_appraisal: false,
+        }
+    }
+}
+
+/// Why PMI stopped being charged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PmiDropOff {
+    /// Borrower requested cancellation at 80% LTV of the original value.
+    BorrowerRequest,
+    /// Automatic termination when the scheduled balance reaches 78% of the original value.
+    Automatic,
+    /// Final termination at the midpoint of the amortization term.
+    Midpoint,
+    /// Cancellation on a new appraisal of the appreciated value.
+    Appraisal,
+    /// A government program's premium period ended, such as 11 years of FHA MIP.
+    ProgramTerm,
+    /// The loan was refinanced at 80% or less of the current value.
+    Refinance,
+}
+
+impl fmt::Display for PmiDropOff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PmiDropOff::BorrowerRequest => "80% LTV",
+            PmiDropOff::Automatic => "78% LTV",
+            PmiDropOff::Midpoint => "midpoint",
+            PmiDropOff::Appraisal => "appraisal",
+            PmiDropOff::ProgramTerm => "MIP term",
+            PmiDropOff::Refinance => "refinance",
+        })
+    }
+}
+
+/// Where the loan stands after a month's payment, for deciding whether PMI can end.
+pub(crate) struct LoanPosition {
+    pub month: u32,
+    pub term_months: u32,
+    pub balance: f64,
+    /// Balance under the original amortization schedule, ignoring prepayments.
+    pub scheduled_balance: f64,
+    pub original_value: f64,
+    pub current_value: f64,
+}
+
+impl PmiCancellation {
+    pub(crate) fn drop_off(&self, position: &LoanPosition) -> Option<PmiDropOff> {
+        let original_ltv = position.balance / position.original_value;
+        let appraised_ltv = position.balance / position.current_value;
+        if self.borrower_request && original_ltv <= 0.80 {
+            Some(PmiDropOff::BorrowerRequest)
+        } else if position.scheduled_balance / position.original_value <= 0.78 {
+            Some(PmiDropOff::Automatic)
+        } else if position.month >= position.term_months.div_ceil(2) {
+            Some(PmiDropOff::Midpoint)
+        } else if self.new_appraisal
+            && ((position.month >= 24 && appraised_ltv <= 0.75) || (position.month >= 60 && appraised_ltv <= 0.80))
+        {
+            Some(PmiDropOff::Appraisal)
+        } else {
+            None
+        }
+    }
+}
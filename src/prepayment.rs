@@ -0,0 +1,123 @@
+use std::io::{self, BufRead};
+
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::Scenario;
+use crate::schedule::{simulate, Schedule};
+
+/// A lump-sum payment toward principal, once or on a repeating cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Prepayment {
+    /// First month the payment is made.
+    pub month: u32,
+    pub amount: f64,
+    /// Repeat every this many months, e.g. `Some(12)` for a yearly tax refund.
+    pub every_months: Option<u32>,
+}
+
+impl Prepayment {
+    pub fn once(month: u32, amount: f64) -> Self {
+        Self { month, amount, every_months: None }
+    }
+
+    pub fn recurring(month: u32, amount: f64, every_months: u32) -> Self {
+        Self { month, amount, every_months: Some(every_months) }
+    }
+
+    fn falls_in(&self, month: u32) -> bool {
+        match self.every_months {
+            _ if month < self.month => false,
+            Some(every) if every > 0 => (month - self.month).is_multiple_of(every),
+            _ => month == self.month,
+        }
+    }
+
+    /// Reads prepayments from `month,amount[,every_months]` lines. Blank lines and a
+    /// non-numeric header are skipped.
+    pub fn from_csv<R: BufRead>(reader: R) -> io::Result<Vec<Self>> {
+        let mut prepayments = Vec::new();
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let parsed = match fields[..] {
+                [month, amount] => Some((month.parse().ok(), amount.parse().ok(), Some(None))),
+                [month, amount, every] => Some((month.parse().ok(), amount.parse().ok(), every.parse().ok().map(Some))),
+                _ => None,
+            };
+            match parsed {
+                Some((Some(month), Some(amount), Some(every_months))) => {
+                    prepayments.push(Prepayment { month, amount, every_months })
+                }
+                _ if line_number == 0 => continue,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("line {}: expected `month,amount[,every_months]`, got `{}`", line_number + 1, line),
+                    ))
+                }
+            }
+        }
+        Ok(prepayments)
+    }
+}
+
+/// Total of all prepayments scheduled for `month`.
+pub(crate) fn prepayment_in(prepayments: &[Prepayment], month: u32) -> f64 {
+    prepayments
+        .iter()
+        .filter(|prepayment| prepayment.falls_in(month))
+        .map(|prepayment| prepayment.amount)
+        .sum()
+}
+
+/// What a set of prepayments buys under one strategy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepaymentOutcome {
+    pub months_to_payoff: u32,
+    pub total_interest_paid: f64,
+    /// Required principal and interest in the payoff month.
+    pub final_required_payment: f64,
+}
+
+impl From<&Schedule> for PrepaymentOutcome {
+    fn from(schedule: &Schedule) -> Self {
+        Self {
+            months_to_payoff: schedule.summary.months_to_payoff,
+            total_interest_paid: schedule.summary.total_interest_paid,
+            final_required_payment: schedule.rows.last().map_or(0.0, |row| row.required_payment),
+        }
+    }
+}
+
+/// The scenario's prepayments applied two ways: keeping the payment so the loan pays off
+/// sooner, or recasting after each one so the payment drops over the original term.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepaymentComparison {
+    pub without_prepayments: PrepaymentOutcome,
+    pub shorten_term: PrepaymentOutcome,
+    pub lower_payment: PrepaymentOutcome,
+}
+
+pub fn compare_prepayments(scenario: &Scenario) -> PrepaymentComparison {
+    let without_prepayments = Scenario {
+        prepayments: Vec::new(),
+        ..scenario.clone()
+    };
+    let shorten_term = Scenario {
+        recast: false,
+        ..scenario.clone()
+    };
+    let lower_payment = Scenario {
+        recast: true,
+        ..scenario.clone()
+    };
+    PrepaymentComparison {
+        without_prepayments: (&simulate(&without_prepayments)).into(),
+        shorten_term: (&simulate(&shorten_term)).into(),
+        lower_payment: (&simulate(&lower_payment)).into(),
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// The loan program, which decides the up-front fee financed into the loan and the
+/// monthly mortgage insurance line.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum LoanProgram {
+    /// Conventional loan with PMI below 20% down, priced from the scenario's `pmi`.
+    #[default]
+    Conventional,
+    /// FHA: 1.75% up-front MIP plus annual MIP, for 11 years at 10% down or more and
+    /// for the life of the loan otherwise.
+    Fha,
+    /// VA: a funding fee tiered by down payment and prior use, and no mortgage insurance.
+    Va {
+        subsequent_use: bool,
+        /// Veterans receiving disability compensation pay no funding fee.
+        exempt: bool,
+    },
+    /// USDA: 1% up-front guarantee fee plus a 0.35% annual fee for the life of the loan.
+    Usda,
+}
+
+/// FHA base loan limit above which the higher annual MIP tiers apply.
+const FHA_HIGH_BALANCE: f64 = 726_200.0;
+
+/// Annual mortgage insurance charged on the remaining balance under a government program.
+pub(crate) struct AnnualInsurance {
+    pub rate: f64,
+    /// Number of months the premium is charged, or `None` for the life of the loan.
+    pub months: Option<u32>,
+}
+
+impl LoanProgram {
+    /// Up-front fee as a fraction of the base loan, given its loan-to-value ratio.
+    pub fn upfront_fee_rate(&self, base_ltv: f64) -> f64 {
+        match *self {
+            LoanProgram::Conventional => 0.0,
+            LoanProgram::Fha => 0.0175,
+            LoanProgram::Va { exempt: true, .. } => 0.0,
+            LoanProgram::Va { subsequent_use, .. } => {
+                // Tiers are compared on LTV so that exactly 10% or 5% down lands in its tier
+                if base_ltv <= 0.90 {
+                    0.0125
+                } else if base_ltv <= 0.95 {
+                    0.015
+                } else if subsequent_use {
+                    0.033
+                } else {
+                    0.0215
+                }
+            }
+            LoanProgram::Usda => 0.01,
+        }
+    }
+
+    /// Annual insurance replacing conventional PMI, or `None` for conventional and VA loans.
+    pub(crate) fn annual_insurance(&self, base_loan: f64, base_ltv: f64, term_months: u32) -> Option<AnnualInsurance> {
+        match self {
+            LoanProgram::Conventional | LoanProgram::Va { .. } => None,
+            LoanProgram::Fha => {
+                let high_balance = base_loan > FHA_HIGH_BALANCE;
+                let rate = match (term_months > 180, high_balance) {
+                    (true, false) if base_ltv > 0.95 => 0.0055,
+                    (true, false) => 0.0050,
+                    (true, true) if base_ltv > 0.95 => 0.0075,
+                    (true, true) => 0.0070,
+                    (false, false) if base_ltv > 0.90 => 0.0040,
+                    (false, false) => 0.0015,
+                    (false, true) if base_ltv > 0.90 => 0.0065,
+                    (false, true) if base_ltv > 0.78 => 0.0040,
+                    (false, true) => 0.0015,
+                };
+                let months = (base_ltv <= 0.90).then_some(11 * 12);
+                Some(AnnualInsurance { rate, months })
+            }
+            LoanProgram::Usda => Some(AnnualInsurance { rate: 0.0035, months: None }),
+        }
+    }
+}
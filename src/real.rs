@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::schedule::{EquitySnapshot, MortgageRow, MortgageSummary, Schedule};
+
+/// Value of a dollar in `month` measured at month zero, for an annual `rate`.
+fn discount(rate: f64, month: u32) -> f64 {
+    (1.0 + rate).powf(-(month as f64) / 12.0)
+}
+
+/// Sums of the schedule's cash flows discounted back to the purchase.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PresentValues {
+    /// Annual rate the cash flows are discounted at.
+    pub discount_rate: f64,
+    pub total_payments: f64,
+    pub total_interest_paid: f64,
+    pub total_waste_cost: f64,
+    pub total_after_tax_waste_cost: f64,
+    pub total_tax_benefit: f64,
+    pub final_equity: f64,
+}
+
+impl MortgageRow {
+    fn scaled(&self, factor: f64) -> Self {
+        Self {
+            interest: self.interest * factor,
+            principal: self.principal * factor,
+            extra_principal: self.extra_principal * factor,
+            prepayment: self.prepayment * factor,
+            repair_costs: self.repair_costs * factor,
+            hoa: self.hoa * factor,
+            taxes: self.taxes * factor,
+            assessed_value: self.assessed_value * factor,
+            insurance: self.insurance * factor,
+            pmi: self.pmi * factor,
+            balloon: self.balloon * factor,
+            refinance_costs: self.refinance_costs * factor,
+            cash_out: self.cash_out * factor,
+            rental_income: self.rental_income * factor,
+            second_lien_interest: self.second_lien_interest * factor,
+            second_lien_principal: self.second_lien_principal * factor,
+            second_lien_debt: self.second_lien_debt * factor,
+            required_payment: self.required_payment * factor,
+            actual_payment: self.actual_payment * factor,
+            cost_of_capital: self.cost_of_capital * factor,
+            waste_cost: self.waste_cost * factor,
+            tax_benefit: self.tax_benefit * factor,
+            after_tax_waste_cost: self.after_tax_waste_cost * factor,
+            cost: self.cost * factor,
+            debt: self.debt * factor,
+            house_cost: self.house_cost * factor,
+            equity: self.equity * factor,
+            ..self.clone()
+        }
+    }
+}
+
+impl Schedule {
+    /// The same schedule in today's dollars: every dollar column of every row is
+    /// deflated by `inflation_rate` to the purchase date and the summary totals are
+    /// re-added from those rows. Rates, ratios and up-front amounts are unchanged.
+    pub fn in_real_dollars(&self, inflation_rate: f64) -> Schedule {
+        let rows: Vec<MortgageRow> = self
+            .rows
+            .iter()
+            .map(|row| row.scaled(discount(inflation_rate, row.month)))
+            .collect();
+        let total = |field: fn(&MortgageRow) -> f64| rows.iter().map(field).sum::<f64>();
+
+        let summary = MortgageSummary {
+            total_interest_paid: total(|row| row.interest),
+            second_lien_interest_paid: total(|row| row.second_lien_interest),
+            total_principal_paid: total(|row| row.principal + row.extra_principal + row.prepayment + row.balloon),
+            total_taxes_paid: total(|row| row.taxes),
+            total_insurance_paid: total(|row| row.insurance),
+            total_maintenance_paid: total(|row| row.repair_costs),
+            total_pmi_paid: total(|row| row.pmi),
+            total_hoa_paid: total(|row| row.hoa),
+            total_rental_income: total(|row| row.rental_income),
+            total_payments: total(|row| row.actual_payment),
+            total_cost_of_capital: total(|row| row.cost_of_capital),
+            total_waste_cost: total(|row| row.waste_cost),
+            total_tax_benefit: total(|row| row.tax_benefit),
+            total_after_tax_waste_cost: total(|row| row.after_tax_waste_cost),
+            final_house_value: rows.last().map_or(self.summary.final_house_value, |row| row.house_cost),
+            final_equity: rows.last().map_or(self.summary.final_equity, |row| row.equity),
+            anniversaries: rows
+                .iter()
+                .filter(|row| row.month % 12 == 0)
+                .map(EquitySnapshot::from)
+                .collect(),
+            balloon_payment: total(|row| row.balloon),
+            ..self.summary.clone()
+        };
+
+        Schedule { rows, summary }
+    }
+
+    /// Discounts the schedule's totals to the purchase date at `discount_rate`.
+    pub fn present_values(&self, discount_rate: f64) -> PresentValues {
+        let total = |field: fn(&MortgageRow) -> f64| {
+            self.rows
+                .iter()
+                .map(|row| field(row) * discount(discount_rate, row.month))
+                .sum::<f64>()
+        };
+        PresentValues {
+            discount_rate,
+            total_payments: total(|row| row.actual_payment),
+            total_interest_paid: total(|row| row.interest),
+            total_waste_cost: total(|row| row.waste_cost),
+            total_after_tax_waste_cost: total(|row| row.after_tax_waste_cost),
+            total_tax_benefit: total(|row| row.tax_benefit),
+            final_equity: self
+                .rows
+                .last()
+                .map_or(0.0, |row| row.equity * discount(discount_rate, row.month)),
+        }
+    }
+}
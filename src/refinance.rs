@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::{LoanTerm, Scenario};
+use crate::schedule::{simulate, MortgageRow};
+
+/// Replaces the first mortgage with a new fixed-rate loan partway through the schedule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Refinance {
+    /// First month paid on the new loan; the old loan is paid off at the end of the
+    /// month before.
+    pub month: u32,
+    /// Annual rate of the new loan.
+    pub rate: f64,
+    pub term: LoanTerm,
+    /// Closing costs paid in cash in the refinance month.
+    pub closing_costs: f64,
+    /// Cash taken out on top of the balance paid off, added to the new loan.
+    pub cash_out: f64,
+}
+
+impl Refinance {
+    /// Months on the new loan; a zero term is paid off in the refinance month itself, so
+    /// the schedule always reaches the refinance.
+    pub(crate) fn term_months(&self) -> u32 {
+        self.term.months().max(1)
+    }
+}
+
+/// A refinance set against keeping the original loan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefinanceAnalysis {
+    pub month: u32,
+    /// First-lien balance the new loan pays off.
+    pub payoff_balance: f64,
+    /// Payoff balance plus any cash out.
+    pub new_loan_amount: f64,
+    /// Required payment in the refinance month without and with refinancing.
+    pub old_payment: f64,
+    pub new_payment: f64,
+    /// Month in which the loan payments and PMI saved since the refinance cover its
+    /// closing costs, or `None` if they never do. Cash out is not counted as a saving.
+    pub break_even_month: Option<u32>,
+    /// Lifetime interest saved; negative when the new loan costs more interest.
+    pub interest_saved: f64,
+    /// After-tax waste cost saved over the months both schedules run, closing costs
+    /// included.
+    pub waste_cost_saved: f64,
+}
+
+/// Runs the scenario with and without its refinance. Returns `None` when the scenario
+/// has no refinance or the loan is gone before the refinance month.
+pub fn analyze_refinance(scenario: &Scenario) -> Option<RefinanceAnalysis> {
+    let refinance = scenario.refinance.as_ref()?;
+    let with = simulate(scenario);
+    let without = simulate(&Scenario {
+        refinance: None,
+        ..scenario.clone()
+    });
+
+    let index = refinance.month.checked_sub(1)? as usize;
+    let payoff_balance = match index.checked_sub(1) {
+        Some(previous) => with.rows.get(previous).map(|row| row.debt - row.second_lien_debt)?,
+        None => scenario.loan_amount(),
+    };
+    let refinanced = with.rows.get(index)?;
+    if payoff_balance <= 0.0 {
+        return None;
+    }
+
+    // Only loan costs are compared, since taxes, insurance and upkeep are the same either
+    // way and would otherwise drop out of whichever schedule ends first
+    let mut savings = 0.0;
+    let break_even_month = with.rows[index..].iter().enumerate().find_map(|(offset, row)| {
+        let kept = without.rows.get(index + offset).map_or(0.0, loan_outlay);
+        savings += kept - loan_outlay(row) - row.refinance_costs;
+        (savings >= 0.0).then_some(row.month)
+    });
+
+    Some(RefinanceAnalysis {
+        month: refinance.month,
+        payoff_balance,
+        new_loan_amount: payoff_balance + refinance.cash_out,
+        old_payment: without.rows.get(index).map_or(0.0, |row| row.required_payment),
+        new_payment: refinanced.required_payment,
+        break_even_month,
+        interest_saved: without.summary.total_interest_paid - with.summary.total_interest_paid,
+        waste_cost_saved: without
+            .rows
+            .iter()
+            .zip(&with.rows)
+            .map(|(kept, refinanced)| kept.after_tax_waste_cost - refinanced.after_tax_waste_cost)
+            .sum(),
+    })
+}
+
+/// Everything paid to the lenders in a month, PMI included.
+fn loan_outlay(row: &MortgageRow) -> f64 {
+    row.required_payment + row.extra_principal + row.prepayment + row.balloon + row.pmi
+}
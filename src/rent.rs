@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::Scenario;
+use crate::schedule::{simulate, Schedule};
+
+/// The renting alternative to a purchase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RentScenario {
+    pub monthly_rent: f64,
+    /// Annual rent increase, applied on each lease anniversary.
+    pub rent_growth: f64,
+    pub renters_insurance_monthly: f64,
+    /// Refundable security deposit, returned at the end of the comparison.
+    pub deposit: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RentRow {
+    pub month: u32,
+    pub rent: f64,
+    pub renters_insurance: f64,
+    /// Everything the renter pays this month.
+    pub rent_cost: f64,
+    /// The buyer's waste cost for the same month.
+    pub buy_waste_cost: f64,
+    pub cumulative_rent_cost: f64,
+    pub cumulative_buy_waste_cost: f64,
+    /// Invested down payment and monthly savings, plus the deposit.
+    pub renter_net_worth: f64,
+    /// Home equity plus any savings invested in months where owning was cheaper.
+    pub buyer_net_worth: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RentComparison {
+    pub rows: Vec<RentRow>,
+    /// First month from which buying stays ahead of renting in net worth.
+    pub break_even_month: Option<u32>,
+}
+
+/// Compares buying under `scenario` with renting under `rent` over the buyer's schedule.
+///
+/// The renter invests the buyer's cash to close (less the deposit), and
+/// each month whichever side pays less invests the difference at the scenario's
+/// after-tax investment return. The buyer's outlay is net of any income tax benefit
+/// and of any cash taken out in a refinance.
+pub fn compare_rent(scenario: &Scenario, rent: &RentScenario) -> RentComparison {
+    let monthly_return = scenario.after_tax_investment_return() / 12.0;
+    compare_rent_schedule(scenario, &simulate(scenario), rent, |_| monthly_return)
+}
+
+/// Compares renting with an already simulated `schedule`, investing at
+/// `monthly_return(month)` in each month.
+pub(crate) fn compare_rent_schedule(
+    scenario: &Scenario,
+    schedule: &Schedule,
+    rent: &RentScenario,
+    monthly_return: impl Fn(u32) -> f64,
+) -> RentComparison {
+    let mut renter_portfolio = scenario.cash_to_close() - rent.deposit;
+    let mut buyer_portfolio = 0.0;
+    let mut cumulative_rent_cost = 0.0;
+    let mut cumulative_buy_waste_cost = 0.0;
+    let mut rows = Vec::with_capacity(schedule.rows.len());
+
+    for buy in &schedule.rows {
+        let lease_year = (buy.month - 1) / 12;
+        let monthly_rent = rent.monthly_rent * (1.0 + rent.rent_growth).powi(lease_year as i32);
+        let rent_cost = monthly_rent + rent.renters_insurance_monthly;
+
+        let monthly_return = monthly_return(buy.month);
+        renter_portfolio *= 1.0 + monthly_return;
+        buyer_portfolio *= 1.0 + monthly_return;
+        let savings = buy.actual_payment - buy.tax_benefit - rent_cost;
+        if savings > 0.0 {
+            renter_portfolio += savings;
+        } else {
+            buyer_portfolio -= savings;
+        }
+
+        cumulative_rent_cost += rent_cost;
+        cumulative_buy_waste_cost += buy.after_tax_waste_cost;
+
+        rows.push(RentRow {
+            month: buy.month,
+            rent: monthly_rent,
+            renters_insurance: rent.renters_insurance_monthly,
+            rent_cost,
+            buy_waste_cost: buy.after_tax_waste_cost,
+            cumulative_rent_cost,
+            cumulative_buy_waste_cost,
+            renter_net_worth: renter_portfolio + rent.deposit,
+            buyer_net_worth: buy.equity + buyer_portfolio,
+        });
+    }
+
+    let break_even_month = match rows.iter().rposition(|row| row.buyer_net_worth < row.renter_net_worth) {
+        Some(last_behind) => rows.get(last_behind + 1).map(|row| row.month),
+        None => rows.first().map(|row| row.month),
+    };
+
+    RentComparison { rows, break_even_month }
+}
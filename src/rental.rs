@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::escalation::Escalation;
+
+/// A unit or room rented out while the owner lives in the rest of the home, such as the
+/// other side of a duplex or an ADU.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RentalUnit {
+    /// Rent when occupied, per month in the first year.
+    pub monthly_rent: f64,
+    /// Fraction of the time the unit sits empty.
+    pub vacancy_rate: f64,
+    /// Annual rent increase, applied on each purchase anniversary.
+    pub rent_growth: f64,
+    /// Property management fee as a fraction of rent collected.
+    pub management_fee: f64,
+    /// Monthly repairs and upkeep the unit adds, escalating with general inflation.
+    pub extra_repairs: f64,
+}
+
+impl RentalUnit {
+    /// Rent collected after vacancy, less management and extra repairs, in a month of
+    /// ownership year `years`.
+    pub(crate) fn net_income(&self, years: u32, inflation_rate: f64) -> f64 {
+        let rent = self.monthly_rent * (1.0 + self.rent_growth).powi(years as i32);
+        let collected = rent * (1.0 - self.vacancy_rate);
+        collected * (1.0 - self.management_fee) - self.extra_repairs * Escalation::factor(None, inflation_rate, years)
+    }
+}
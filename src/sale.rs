@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::Scenario;
+use crate::schedule::{MortgageRow, Schedule};
+
+/// Selling the home after a holding period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaleAssumptions {
+    pub holding_months: u32,
+    /// Realtor commission as a fraction of the sale price.
+    pub commission_rate: f64,
+    /// Transfer taxes as a fraction of the sale price.
+    pub transfer_tax_rate: f64,
+    /// Any other fixed selling costs.
+    pub other_costs: f64,
+}
+
+impl Default for SaleAssumptions {
+    fn default() -> Self {
+        Self {
+            holding_months: 7 * 12,
+            commission_rate: 0.06,
+            transfer_tax_rate: 0.01,
+            other_costs: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaleAnalysis {
+    pub month: u32,
+    pub sale_price: f64,
+    pub commission: f64,
+    pub transfer_taxes: f64,
+    pub other_costs: f64,
+    pub remaining_debt: f64,
+    pub net_proceeds: f64,
+    /// Cash to close plus every monthly payment through the sale month, net of the income
+    /// tax benefit and any cash taken out in a refinance.
+    pub total_cash_invested: f64,
+    /// Net proceeds less total cash invested.
+    pub total_return: f64,
+    /// Annualized internal rate of return on the buyer's cash flows.
+    pub irr: Option<f64>,
+}
+
+/// Sells the home at the end of `sale.holding_months`, using the schedule's rows for the
+/// home value, remaining debt and monthly outlays. Returns `None` when the holding period
+/// is zero or runs past the end of the schedule.
+pub fn analyze_sale(scenario: &Scenario, schedule: &Schedule, sale: &SaleAssumptions) -> Option<SaleAnalysis> {
+    let months = sale.holding_months as usize;
+    if months == 0 {
+        return None;
+    }
+    let rows = schedule.rows.get(..months)?;
+    let last = rows.last()?;
+
+    let sale_price = last.house_cost;
+    let commission = sale_price * sale.commission_rate;
+    let transfer_taxes = sale_price * sale.transfer_tax_rate;
+    let net_proceeds = sale_price - commission - transfer_taxes - sale.other_costs - last.debt;
+
+    // Monthly outlays are after tax, as in the rent comparison
+    let outlay = |row: &MortgageRow| row.actual_payment - row.tax_benefit;
+    let mut cash_flows = Vec::with_capacity(months + 1);
+    cash_flows.push(-scenario.cash_to_close());
+    cash_flows.extend(rows.iter().map(|row| -outlay(row)));
+    *cash_flows.last_mut()? += net_proceeds;
+
+    let total_cash_invested = scenario.cash_to_close() + rows.iter().map(outlay).sum::<f64>();
+
+    Some(SaleAnalysis {
+        month: last.month,
+        sale_price,
+        commission,
+        transfer_taxes,
+        other_costs: sale.other_costs,
+        remaining_debt: last.debt,
+        net_proceeds,
+        total_cash_invested,
+        total_return: net_proceeds - total_cash_invested,
+        irr: irr(&cash_flows).map(|monthly| (1.0 + monthly).powi(12) - 1.0),
+    })
+}
+
+/// Periodic internal rate of return of `cash_flows`, where index 0 is today. Returns
+/// `None` when the flows never change sign across the searched range of rates.
+pub fn irr(cash_flows: &[f64]) -> Option<f64> {
+    let npv = |rate: f64| {
+        cash_flows
+            .iter()
+            .enumerate()
+            .map(|(period, flow)| flow / (1.0 + rate).powi(period as i32))
+            .sum::<f64>()
+    };
+
+    let (mut low, mut high) = (-0.5, 0.5);
+    let (mut npv_low, npv_high) = (npv(low), npv(high));
+    if !npv_low.is_finite() || !npv_high.is_finite() || npv_low.signum() == npv_high.signum() {
+        return None;
+    }
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        let npv_mid = npv(mid);
+        if npv_mid.signum() == npv_low.signum() {
+            low = mid;
+            npv_low = npv_mid;
+        } else {
+            high = mid;
+        }
+        if high - low < 1e-12 {
+            break;
+        }
+    }
+    Some((low + high) / 2.0)
+}
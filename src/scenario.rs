@@ -0,0 +1,262 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arm::Arm;
+use crate::assessment::Assessment;
+use crate::closing::ClosingCosts;
+use crate::escalation::Escalation;
+use crate::frequency::PaymentFrequency;
+use crate::lien::SecondLien;
+use crate::money::Rounding;
+use crate::pmi::PmiCancellation;
+use crate::prepayment::Prepayment;
+use crate::program::LoanProgram;
+use crate::refinance::Refinance;
+use crate::rental::RentalUnit;
+use crate::tax::TaxProfile;
+
+/// A cost entered either as a rate against some base value or as a fixed dollar amount.
+///
+/// Rates are fractions (`0.02` for 2%). What the rate applies to, and whether a fixed
+/// amount is annual or monthly, is documented on each [`Scenario`] field.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Cost {
+    Rate(f64),
+    Fixed(f64),
+}
+
+/// Length of the loan, entered either in (possibly fractional) years or in months.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LoanTerm {
+    Years(f64),
+    Months(u32),
+}
+
+impl LoanTerm {
+    /// Number of monthly payments, rounding fractional years to the nearest month.
+    pub fn months(self) -> u32 {
+        match self {
+            LoanTerm::Years(years) => (years * 12.0).round().max(0.0) as u32,
+            LoanTerm::Months(months) => months,
+        }
+    }
+}
+
+/// Why a [`Scenario`] cannot be scheduled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InvalidScenario {
+    /// The loan term rounds to zero months.
+    ZeroLoanTerm,
+    /// The balloon falls due in month 0, before any payment.
+    ZeroBalloonMonth,
+}
+
+impl fmt::Display for InvalidScenario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InvalidScenario::ZeroLoanTerm => "loan term must be at least one month",
+            InvalidScenario::ZeroBalloonMonth => "balloon must fall due in month 1 or later",
+        })
+    }
+}
+
+impl std::error::Error for InvalidScenario {}
+
+/// Typed, already-parsed inputs for a single home purchase.
+///
+/// All rates are annual fractions (`0.065` for 6.5%).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scenario {
+    /// Purchase price of the home.
+    pub house_value: f64,
+    /// Rate of the purchase price, or fixed dollars.
+    pub down_payment: Cost,
+    #[serde(default)]
+    pub loan_program: LoanProgram,
+    /// Monthly HOA fee.
+    pub hoa_monthly: f64,
+    /// Units or rooms rented out, whose net rent offsets the cost of owning.
+    #[serde(default)]
+    pub rental_units: Vec<RentalUnit>,
+    /// Annual mortgage interest rate before points and credits; the initial rate when
+    /// `arm` is set.
+    pub interest_rate: f64,
+    /// Adjustable-rate terms, or `None` for a fixed-rate loan.
+    #[serde(default)]
+    pub arm: Option<Arm>,
+    /// Second mortgage or HELOC taken out at purchase, or `None` for a single loan. It
+    /// finances part of the price alongside the down payment, so the first lien is smaller.
+    #[serde(default)]
+    pub second_lien: Option<SecondLien>,
+    #[serde(default)]
+    pub closing_costs: ClosingCosts,
+    /// Annual rate of current home value, or fixed annual dollars.
+    pub property_tax: Cost,
+    /// Assessment rules for a percentage property tax.
+    #[serde(default)]
+    pub assessment: Assessment,
+    /// Annual rate of current home value, or fixed annual dollars.
+    pub insurance: Cost,
+    /// Annual rate of current home value, or fixed annual dollars.
+    pub maintenance: Cost,
+    /// Annual rate of the remaining loan balance, or fixed monthly dollars.
+    /// Only charged on conventional loans when the first lien is over 80% of the price,
+    /// until cancelled.
+    pub pmi: Cost,
+    #[serde(default)]
+    pub pmi_cancellation: PmiCancellation,
+    /// Annual change in home value; may be negative.
+    pub appreciation_rate: f64,
+    /// General annual inflation, the default escalation for fixed-dollar cost lines.
+    #[serde(default)]
+    pub inflation_rate: f64,
+    #[serde(default)]
+    pub escalation: Escalation,
+    /// Annual return the equity would earn if invested elsewhere; drives cost of capital.
+    pub investment_return: f64,
+    /// Tax rate on `investment_return`; zero treats the return as pre-tax.
+    #[serde(default)]
+    pub investment_tax_rate: f64,
+    /// Federal income tax profile, or `None` to treat interest and property tax as
+    /// fully non-deductible.
+    #[serde(default)]
+    pub tax: Option<TaxProfile>,
+    pub loan_term: LoanTerm,
+    /// Months at the start of the loan in which only interest is due; the balance then
+    /// amortizes over the rest of `loan_term`.
+    #[serde(default)]
+    pub interest_only_months: u32,
+    /// Month the remaining balance falls due, for a balloon loan that amortizes over
+    /// `loan_term` but matures sooner; `None` for a fully amortizing loan. A month at or
+    /// after the end of the term is no balloon at all.
+    #[serde(default)]
+    pub balloon_month: Option<u32>,
+    /// How often the payment is made; the schedule still has one row per month.
+    #[serde(default)]
+    pub payment_frequency: PaymentFrequency,
+    /// Additional principal paid every month.
+    pub extra_principal: f64,
+    /// Lump sums paid toward principal on top of `extra_principal`.
+    #[serde(default)]
+    pub prepayments: Vec<Prepayment>,
+    /// Re-amortize the lower balance over the remaining term after each prepayment so the
+    /// required payment drops; otherwise the payment stays and the term shortens.
+    #[serde(default)]
+    pub recast: bool,
+    /// New loan that replaces the first mortgage partway through, or `None` to keep it.
+    #[serde(default)]
+    pub refinance: Option<Refinance>,
+    /// Cent rounding for the payment, interest and principal.
+    #[serde(default)]
+    pub rounding: Rounding,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self {
+            house_value: 0.0,
+            down_payment: Cost::Rate(0.20),
+            loan_program: LoanProgram::Conventional,
+            hoa_monthly: 0.0,
+            rental_units: Vec::new(),
+            interest_rate: 0.065,
+            arm: None,
+            second_lien: None,
+            closing_costs: ClosingCosts::default(),
+            property_tax: Cost::Rate(0.02),
+            assessment: Assessment::default(),
+            insurance: Cost::Rate(0.0035),
+            maintenance: Cost::Rate(0.01),
+            pmi: Cost::Rate(0.005),
+            pmi_cancellation: PmiCancellation::default(),
+            appreciation_rate: 0.03,
+            inflation_rate: 0.0,
+            escalation: Escalation::default(),
+            investment_return: 0.07,
+            investment_tax_rate: 0.0,
+            tax: None,
+            loan_term: LoanTerm::Years(30.0),
+            interest_only_months: 0,
+            balloon_month: None,
+            payment_frequency: PaymentFrequency::Monthly,
+            extra_principal: 0.0,
+            prepayments: Vec::new(),
+            recast: false,
+            refinance: None,
+            rounding: Rounding::default(),
+        }
+    }
+}
+
+impl Scenario {
+    /// Checks the inputs a schedule cannot be built without. [`simulate`] gives an empty
+    /// schedule for a scenario that fails.
+    ///
+    /// [`simulate`]: crate::simulate
+    pub fn validate(&self) -> Result<(), InvalidScenario> {
+        if self.loan_term.months() == 0 {
+            return Err(InvalidScenario::ZeroLoanTerm);
+        }
+        if self.balloon_month == Some(0) {
+            return Err(InvalidScenario::ZeroBalloonMonth);
+        }
+        Ok(())
+    }
+
+    pub fn down_payment(&self) -> f64 {
+        match self.down_payment {
+            Cost::Rate(rate) => self.house_value * rate,
+            Cost::Fixed(amount) => amount,
+        }
+    }
+
+    /// Amount borrowed on the second lien at purchase.
+    pub fn second_lien_amount(&self) -> f64 {
+        match self.second_lien.as_ref().map(|lien| lien.amount) {
+            Some(Cost::Rate(rate)) => self.house_value * rate,
+            Some(Cost::Fixed(amount)) => amount,
+            None => 0.0,
+        }
+    }
+
+    /// Purchase price less down payment and any second lien, before any financed program
+    /// fee.
+    pub fn base_loan_amount(&self) -> f64 {
+        self.house_value - self.down_payment() - self.second_lien_amount()
+    }
+
+    /// First-lien loan-to-value at purchase.
+    pub fn base_ltv(&self) -> f64 {
+        self.base_loan_amount() / self.house_value
+    }
+
+    /// FHA up-front MIP, VA funding fee or USDA guarantee fee, financed into the loan.
+    pub fn upfront_program_fee(&self) -> f64 {
+        self.base_loan_amount() * self.loan_program.upfront_fee_rate(self.base_ltv())
+    }
+
+    /// First-lien loan amount.
+    pub fn loan_amount(&self) -> f64 {
+        self.base_loan_amount() + self.upfront_program_fee()
+    }
+
+    /// Interest rate after discount points and lender credits.
+    pub fn note_rate(&self) -> f64 {
+        self.interest_rate + self.closing_costs.rate_adjustment()
+    }
+
+    /// Closing costs, points and credits net of seller concessions.
+    pub fn net_closing_costs(&self) -> f64 {
+        self.closing_costs.net_cost(self.loan_amount())
+    }
+
+    /// Down payment plus net closing costs.
+    pub fn cash_to_close(&self) -> f64 {
+        self.down_payment() + self.net_closing_costs()
+    }
+
+    pub fn after_tax_investment_return(&self) -> f64 {
+        self.investment_return * (1.0 - self.investment_tax_rate)
+    }
+}
@@ -0,0 +1,733 @@
+use std::io::{self, Write};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::escalation::Escalation;
+use crate::lien::{LienPayment, SecondLienLoan};
+use crate::money::{decimal, to_f64};
+use crate::montecarlo::MarketPath;
+use crate::pmi::{LoanPosition, PmiDropOff};
+use crate::prepayment::prepayment_in;
+use crate::program::LoanProgram;
+use crate::scenario::{Cost, Scenario};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MortgageRow {
+    pub month: u32,
+    /// Interest across all liens.
+    pub interest: f64,
+    /// Scheduled principal across all liens.
+    pub principal: f64,
+    pub extra_principal: f64,
+    /// Lump-sum prepayments made this month.
+    pub prepayment: f64,
+    pub repair_costs: f64,
+    pub hoa: f64,
+    pub taxes: f64,
+    /// Value the property tax is assessed on, before any exemption.
+    pub assessed_value: f64,
+    pub insurance: f64,
+    pub pmi: f64,
+    /// Set on the first month PMI is no longer charged.
+    pub pmi_dropoff: Option<PmiDropOff>,
+    /// Balance still owing on any lien that falls due this month, paid off in that month.
+    pub balloon: f64,
+    /// Closing costs of a refinance made this month.
+    pub refinance_costs: f64,
+    /// Cash received from a cash-out refinance this month; already netted out of
+    /// `actual_payment`.
+    pub cash_out: f64,
+    /// Rent from rental units after vacancy, management and their extra repairs; already
+    /// subtracted from `actual_payment` and `waste_cost`.
+    pub rental_income: f64,
+    /// Principal and interest the servicers require across this month's payments on all
+    /// liens, before extra principal and prepayments.
+    pub required_payment: f64,
+    pub actual_payment: f64,
+    pub cost_of_capital: f64,
+    pub waste_cost: f64,
+    /// Federal tax saved by itemizing this month's share of interest and property tax.
+    pub tax_benefit: f64,
+    pub after_tax_waste_cost: f64,
+    pub cost: f64,
+    /// All lien balances, after this month's payment.
+    pub debt: f64,
+    /// First-lien interest rate.
+    pub interest_rate: f64,
+    pub house_cost: f64,
+    /// Home value less all lien balances, after this month's payment.
+    pub equity: f64,
+    /// First-lien balance over home value, after this month's payment.
+    pub ltv: f64,
+    /// All lien balances over home value, after this month's payment.
+    pub combined_ltv: f64,
+    /// Second-lien share of `interest`.
+    pub second_lien_interest: f64,
+    /// Second-lien share of `principal`.
+    pub second_lien_principal: f64,
+    /// Second-lien share of `debt`.
+    pub second_lien_debt: f64,
+    /// Second-lien interest rate.
+    pub second_lien_rate: f64,
+}
+
+/// Equity position at the end of a given month.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EquitySnapshot {
+    pub month: u32,
+    pub house_value: f64,
+    pub debt: f64,
+    pub equity: f64,
+    pub ltv: f64,
+    pub combined_ltv: f64,
+}
+
+impl From<&MortgageRow> for EquitySnapshot {
+    fn from(row: &MortgageRow) -> Self {
+        Self {
+            month: row.month,
+            house_value: row.house_cost,
+            debt: row.debt,
+            equity: row.equity,
+            ltv: row.ltv,
+            combined_ltv: row.combined_ltv,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MortgageSummary {
+    /// Interest across all liens.
+    pub total_interest_paid: f64,
+    /// Second-lien share of `total_interest_paid`.
+    pub second_lien_interest_paid: f64,
+    pub total_principal_paid: f64,
+    pub total_taxes_paid: f64,
+    pub total_insurance_paid: f64,
+    pub total_maintenance_paid: f64,
+    pub total_pmi_paid: f64,
+    pub total_hoa_paid: f64,
+    /// Net rent from rental units.
+    pub total_rental_income: f64,
+    pub total_payments: f64,
+    pub total_cost_of_capital: f64,
+    pub total_waste_cost: f64,
+    pub total_tax_benefit: f64,
+    pub total_after_tax_waste_cost: f64,
+    pub final_house_value: f64,
+    pub final_equity: f64,
+    /// Equity at the end of every twelfth month through payoff.
+    pub anniversaries: Vec<EquitySnapshot>,
+    pub months_to_payoff: u32,
+    pub effective_interest_rate: f64,
+    /// Balance the regular payments left unpaid when the loan fell due, to be paid in
+    /// cash or refinanced.
+    pub balloon_payment: f64,
+    /// Month the balloon falls due.
+    pub balloon_month: Option<u32>,
+    /// First month without PMI, if PMI was charged and ended before payoff.
+    pub pmi_dropoff_month: Option<u32>,
+    /// Up-front program fee financed into the loan.
+    pub upfront_program_fee: f64,
+    /// Closing costs, points and credits net of seller concessions.
+    pub closing_costs: f64,
+    /// Down payment plus net closing costs.
+    pub cash_to_close: f64,
+    /// Month in which the payment savings from discount points repay their net cost.
+    pub points_break_even_month: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Schedule {
+    pub rows: Vec<MortgageRow>,
+    pub summary: MortgageSummary,
+}
+
+/// Standard fully-amortizing payment for `principal` over `num_payments` periods.
+pub(crate) fn amortizing_payment(principal: f64, periodic_rate: f64, num_payments: f64) -> f64 {
+    if periodic_rate > 0.0 {
+        principal * (periodic_rate * (1.0 + periodic_rate).powf(num_payments))
+            / ((1.0 + periodic_rate).powf(num_payments) - 1.0)
+    } else {
+        principal / num_payments
+    }
+}
+
+/// Balance after `month` payments of `payment` with no prepayments.
+fn scheduled_balance(principal: f64, periodic_rate: f64, payment: f64, month: u32) -> f64 {
+    if periodic_rate > 0.0 {
+        let growth = (1.0 + periodic_rate).powi(month as i32);
+        principal * growth - payment * (growth - 1.0) / periodic_rate
+    } else {
+        principal - payment * month as f64
+    }
+}
+
+/// Runs the month-by-month amortization for `scenario`, which should pass
+/// [`Scenario::validate`].
+pub fn simulate(scenario: &Scenario) -> Schedule {
+    simulate_path(scenario, None)
+}
+
+/// Runs the amortization with appreciation and investment returns taken year by year
+/// from `path` instead of the scenario's constant rates.
+pub(crate) fn simulate_path(scenario: &Scenario, path: Option<&MarketPath>) -> Schedule {
+    let house_value = scenario.house_value;
+    let initial_rate = scenario.note_rate();
+    let mut annual_interest_rate = initial_rate;
+
+    let down_payment = scenario.down_payment();
+    let loan_amount = scenario.loan_amount();
+    // A second lien counts toward the 20% that keeps the first lien free of PMI
+    let down_payment_percent = (down_payment + scenario.second_lien_amount()) / house_value;
+
+    // The loan itself is kept in exact cents, rounded the way the servicer rounds
+    let rounding = scenario.rounding;
+    let extra_principal_payment = rounding.cents(decimal(scenario.extra_principal));
+    let mut balance = rounding.cents(decimal(loan_amount));
+    let mut second_lien = scenario
+        .second_lien
+        .as_ref()
+        .map(|lien| SecondLienLoan::new(lien, scenario.second_lien_amount(), rounding));
+
+    // Calculate the payment using standard mortgage formula
+    let mut num_payments = scenario.loan_term.months();
+    let frequency = scenario.payment_frequency;
+    let periods_per_year = Decimal::from(frequency.periods_per_year());
+    let mut final_period = frequency.last_period(num_payments);
+    // A balloon loan amortizes over the full term but falls due sooner
+    let due_month = scenario
+        .balloon_month
+        .filter(|month| (1..num_payments).contains(month))
+        .unwrap_or(num_payments);
+    let mut due_period = frequency.last_period(due_month);
+    let mut interest_only_months = scenario.interest_only_months;
+    let mut arm = scenario.arm.as_ref();
+    // A refinance while the loan is still outstanding replaces it with a new loan, so
+    // months now count from the start of whichever loan is current
+    let refinance = scenario
+        .refinance
+        .as_ref()
+        .filter(|refinance| (1..=due_month).contains(&refinance.month));
+    let mut loan_start = 0;
+    // The schedule runs until both liens are repaid
+    let last_month = refinance
+        .map_or(due_month, |refinance| refinance.month - 1 + refinance.term_months())
+        .max(scenario.second_lien.as_ref().map_or(0, |lien| lien.term.months()));
+    let mut period_payment = rounding.cents(decimal(frequency.payment(
+        to_f64(balance),
+        annual_interest_rate,
+        num_payments,
+        final_period,
+    )));
+    // PMI scheduling follows the original monthly amortization whatever the frequency,
+    // starting once any interest-only period ends
+    let mut pmi_loan = loan_amount;
+    let mut pmi_rate = initial_rate / 12.0;
+    let mut pmi_value = house_value;
+    let mut original_payment = to_f64(rounding.cents(decimal(amortizing_payment(
+        pmi_loan,
+        pmi_rate,
+        num_payments.saturating_sub(interest_only_months).max(1) as f64,
+    ))));
+
+    // Government programs charge their own annual premium in place of PMI
+    let mut program_insurance =
+        scenario.loan_program.annual_insurance(scenario.base_loan_amount(), scenario.base_ltv(), num_payments);
+
+    // Conventional PMI only applies if down payment < 20%, and ends under the cancellation rules
+    let mut pmi_active = match (&program_insurance, scenario.loan_program) {
+        (Some(_), _) => true,
+        (None, LoanProgram::Conventional) => down_payment_percent < 0.20,
+        (None, _) => false,
+    };
+    let mut pending_pmi_dropoff = None;
+    let mut pmi_dropoff_month = None;
+
+    let mut rows = Vec::new();
+    let mut remaining_balance = to_f64(balance);
+    let mut second_lien_balance = second_lien.as_ref().map_or(0.0, |lien| lien.balance());
+    let mut current_house_value = house_value;
+    let mut assessed_value = house_value;
+
+    // Summary tracking variables
+    let mut total_interest = Decimal::ZERO;
+    let mut second_lien_interest_paid = Decimal::ZERO;
+    let mut total_principal = Decimal::ZERO;
+    let mut total_taxes = 0.0;
+    let mut total_insurance = 0.0;
+    let mut total_maintenance = 0.0;
+    let mut total_pmi = 0.0;
+    let mut total_hoa = 0.0;
+    let mut total_rental_income = 0.0;
+    let mut total_payments = 0.0;
+    let mut total_cost_of_capital = 0.0;
+    let mut total_waste_cost = 0.0;
+    let mut balloon_payment = Decimal::ZERO;
+    let mut balloon_month = None;
+    let mut actual_months = 0;
+
+    for month in 1..=last_month {
+        if balance.is_zero() && second_lien.as_ref().is_none_or(|lien| lien.is_paid_off()) {
+            break;
+        }
+
+        let periods = frequency.periods_in_month(month);
+
+        // The new loan pays off the old balance plus any cash out and starts its own term
+        let mut refinance_costs = 0.0;
+        let mut cash_out = 0.0;
+        if let Some(refinance) = refinance.filter(|refinance| refinance.month == month && !balance.is_zero()) {
+            balance += rounding.cents(decimal(refinance.cash_out));
+            remaining_balance = to_f64(balance);
+            annual_interest_rate = refinance.rate;
+            arm = None;
+            interest_only_months = 0;
+            loan_start = month - 1;
+            num_payments = loan_start + refinance.term_months();
+            final_period = frequency.last_period(num_payments);
+            due_period = final_period;
+            period_payment = rounding.cents(decimal(frequency.payment(
+                remaining_balance,
+                annual_interest_rate,
+                refinance.term_months(),
+                final_period - periods.start() + 1,
+            )));
+
+            // The new loan is conventional: PMI is charged only above 80% of today's value
+            // and is scheduled from the new loan
+            pmi_loan = remaining_balance;
+            pmi_rate = annual_interest_rate / 12.0;
+            pmi_value = current_house_value;
+            original_payment = to_f64(rounding.cents(decimal(amortizing_payment(
+                pmi_loan,
+                pmi_rate,
+                refinance.term_months() as f64,
+            ))));
+            program_insurance = None;
+            let had_pmi = pmi_active;
+            pmi_active = remaining_balance / current_house_value > 0.80;
+            if had_pmi && !pmi_active {
+                pending_pmi_dropoff = Some(PmiDropOff::Refinance);
+            }
+            refinance_costs = refinance.closing_costs;
+            cash_out = refinance.cash_out;
+        }
+
+        // Adjustable rates reset and the payment re-amortizes over the remaining term
+        if let Some(arm) = arm {
+            if arm.is_reset_month(month) && !balance.is_zero() {
+                annual_interest_rate = arm.adjusted_rate(month, annual_interest_rate, initial_rate);
+                period_payment = rounding.cents(decimal(frequency.payment(
+                    remaining_balance,
+                    annual_interest_rate,
+                    num_payments - month + 1,
+                    final_period - periods.start() + 1,
+                )));
+            }
+        }
+
+        // Amortization over the rest of the term starts when the interest-only period ends
+        if interest_only_months > 0 && month == interest_only_months + 1 && !balance.is_zero() {
+            period_payment = rounding.cents(decimal(frequency.payment(
+                remaining_balance,
+                annual_interest_rate,
+                num_payments - month + 1,
+                final_period - periods.start() + 1,
+            )));
+        }
+        let interest_only = month <= interest_only_months;
+
+        // Each payment in the month accrues interest at the periodic rate on what is owed
+        let rate = decimal(annual_interest_rate);
+        let mut owed = balance;
+        let mut interest = Decimal::ZERO;
+        let mut principal = Decimal::ZERO;
+        let mut extra = Decimal::ZERO;
+        let mut lump_sum = Decimal::ZERO;
+        let mut balloon = Decimal::ZERO;
+        let mut required = Decimal::ZERO;
+        for period in periods.clone() {
+            if owed.is_zero() {
+                break;
+            }
+            let period_interest = rounding.cents(owed * rate / periods_per_year);
+            let scheduled = if interest_only { period_interest } else { period_payment };
+
+            // Ensure we don't overpay
+            let mut period_principal = (scheduled - period_interest).min(owed);
+            let mut paid_ahead = Decimal::ZERO;
+            if period == *periods.start() {
+                extra = extra_principal_payment.min(owed - period_principal);
+                lump_sum = rounding
+                    .cents(decimal(prepayment_in(&scenario.prepayments, month)))
+                    .min(owed - period_principal - extra);
+                paid_ahead = extra + lump_sum;
+            }
+
+            // Cent rounding leaves a small residual when the loan falls due that the final
+            // payment absorbs; anything beyond a regular payment's worth is a balloon
+            if period == due_period {
+                let unpaid = owed - period_principal - paid_ahead;
+                if unpaid > scheduled {
+                    balloon = unpaid;
+                } else {
+                    period_principal += unpaid;
+                }
+            }
+
+            owed -= period_principal + paid_ahead + balloon;
+            interest += period_interest;
+            principal += period_principal;
+            required += scheduled;
+        }
+
+        let second = second_lien.as_mut().map_or(LienPayment::default(), |lien| lien.pay(month));
+
+        let interest_payment = to_f64(interest + second.interest);
+        let principal_payment = to_f64(principal + second.principal);
+        let extra_principal = to_f64(extra);
+        let prepayment = to_f64(lump_sum);
+        let balloon_due = balloon + second.balloon;
+
+        // Calculate monthly costs
+        let years = (month - 1) / 12;
+        let (appreciation_rate, investment_return) = match path {
+            Some(path) => path.rates(years),
+            None => (scenario.appreciation_rate, scenario.investment_return),
+        };
+        current_house_value *= 1.0 + appreciation_rate / 12.0;
+        let monthly_investment_return = investment_return * (1.0 - scenario.investment_tax_rate) / 12.0;
+
+        // Fixed-dollar lines step up with their escalation rate on each anniversary
+        let escalation = &scenario.escalation;
+        let escalate = |line: Option<f64>| Escalation::factor(line, scenario.inflation_rate, years);
+
+        let hoa_monthly = scenario.hoa_monthly * escalate(escalation.hoa);
+        assessed_value = scenario.assessment.assessed_value(month, current_house_value, assessed_value);
+        let monthly_taxes = monthly_home_cost(
+            scenario.property_tax,
+            scenario.assessment.taxable_value(assessed_value),
+            escalate(escalation.property_tax),
+        );
+        let monthly_insurance = monthly_home_cost(scenario.insurance, current_house_value, escalate(escalation.insurance));
+        let monthly_repairs = monthly_home_cost(scenario.maintenance, current_house_value, escalate(escalation.maintenance));
+
+        let rental_income: f64 = scenario
+            .rental_units
+            .iter()
+            .map(|unit| unit.net_income(years, scenario.inflation_rate))
+            .sum();
+
+        let pmi_dropoff = pending_pmi_dropoff.take();
+        if pmi_dropoff.is_some() {
+            pmi_dropoff_month = Some(month);
+        }
+
+        let monthly_pmi = if pmi_active && remaining_balance > 0.0 {
+            match (&program_insurance, scenario.pmi) {
+                (Some(insurance), _) => remaining_balance * insurance.rate / 12.0,
+                (None, Cost::Rate(rate)) => remaining_balance * rate / 12.0,
+                (None, Cost::Fixed(amount)) => amount * escalate(escalation.pmi),
+            }
+        } else {
+            0.0
+        };
+
+        let total_payment = interest_payment + principal_payment + extra_principal + prepayment + to_f64(balloon_due) +
+                           monthly_repairs + hoa_monthly + monthly_taxes + monthly_insurance + monthly_pmi +
+                           refinance_costs - cash_out - rental_income;
+
+        // Cost of capital (opportunity cost) on the equity tied up during the month
+        let cost_of_capital =
+            (current_house_value - remaining_balance - second_lien_balance) * monthly_investment_return;
+
+        // Waste cost = all non-principal payments, offset by any net rent
+        let waste_cost = interest_payment + monthly_repairs + hoa_monthly + monthly_taxes +
+                        monthly_insurance + monthly_pmi + refinance_costs + cost_of_capital - rental_income;
+
+        // Total cost; cash out is borrowed, like principal in reverse, so it is not a saving
+        let total_cost = total_payment - principal_payment - extra_principal - prepayment - to_f64(balloon_due) +
+            cash_out + cost_of_capital;
+
+        let required_payment = to_f64(required + second.interest + second.principal);
+        balance -= principal + extra + lump_sum + balloon;
+        remaining_balance = to_f64(balance);
+        second_lien_balance = second_lien.as_ref().map_or(0.0, |lien| lien.balance());
+        let debt = remaining_balance + second_lien_balance;
+
+        // A recast re-amortizes what is left over the remaining term, lowering the payment
+        if scenario.recast && !lump_sum.is_zero() && !balance.is_zero() && month < num_payments {
+            period_payment = rounding.cents(decimal(frequency.payment(
+                remaining_balance,
+                annual_interest_rate,
+                num_payments - month,
+                final_period - periods.end(),
+            )));
+        }
+
+        if let (true, Some(insurance)) = (pmi_active, &program_insurance) {
+            pending_pmi_dropoff = insurance
+                .months
+                .filter(|&months| month >= months)
+                .map(|_| PmiDropOff::ProgramTerm);
+            pmi_active = pending_pmi_dropoff.is_none();
+        } else if pmi_active {
+            let position = LoanPosition {
+                month: month - loan_start,
+                term_months: num_payments - loan_start,
+                balance: remaining_balance,
+                scheduled_balance: scheduled_balance(
+                    pmi_loan,
+                    pmi_rate,
+                    original_payment,
+                    (month - loan_start).saturating_sub(interest_only_months),
+                ),
+                original_value: pmi_value,
+                current_value: current_house_value,
+            };
+            pending_pmi_dropoff = scenario.pmi_cancellation.drop_off(&position);
+            pmi_active = pending_pmi_dropoff.is_none();
+        }
+
+        // Update summary totals
+        total_interest += interest + second.interest;
+        second_lien_interest_paid += second.interest;
+        total_principal += principal + second.principal + extra + lump_sum + balloon_due;
+        balloon_payment += balloon_due;
+        if !balloon_due.is_zero() {
+            balloon_month = Some(month);
+        }
+        total_taxes += monthly_taxes;
+        total_insurance += monthly_insurance;
+        total_maintenance += monthly_repairs;
+        total_pmi += monthly_pmi;
+        total_hoa += hoa_monthly;
+        total_rental_income += rental_income;
+        total_payments += total_payment;
+        total_cost_of_capital += cost_of_capital;
+        total_waste_cost += waste_cost;
+        actual_months = month;
+
+        rows.push(MortgageRow {
+            month,
+            interest: interest_payment,
+            principal: principal_payment,
+            extra_principal,
+            prepayment,
+            repair_costs: monthly_repairs,
+            hoa: hoa_monthly,
+            taxes: monthly_taxes,
+            assessed_value,
+            insurance: monthly_insurance,
+            pmi: monthly_pmi,
+            pmi_dropoff,
+            balloon: to_f64(balloon_due),
+            refinance_costs,
+            cash_out,
+            rental_income,
+            required_payment,
+            actual_payment: total_payment,
+            cost_of_capital,
+            waste_cost,
+            tax_benefit: 0.0,
+            after_tax_waste_cost: waste_cost,
+            cost: total_cost,
+            debt,
+            interest_rate: annual_interest_rate,
+            house_cost: current_house_value,
+            equity: current_house_value - debt,
+            ltv: remaining_balance / current_house_value,
+            combined_ltv: debt / current_house_value,
+            second_lien_interest: to_f64(second.interest),
+            second_lien_principal: to_f64(second.principal),
+            second_lien_debt: second_lien_balance,
+            second_lien_rate: second.rate,
+        });
+    }
+
+    if let Some(tax) = &scenario.tax {
+        tax.apply(&mut rows);
+    }
+    let total_tax_benefit: f64 = rows.iter().map(|row| row.tax_benefit).sum();
+
+    // Calculate summary statistics
+    let final_house_value = current_house_value;
+    let final_equity = final_house_value - remaining_balance - second_lien_balance;
+    let total_interest = to_f64(total_interest);
+    let total_principal = to_f64(total_principal);
+    let anniversaries = rows
+        .iter()
+        .filter(|row| row.month % 12 == 0)
+        .map(EquitySnapshot::from)
+        .collect();
+    let effective_interest_rate = if total_principal > 0.0 {
+        (total_interest / total_principal) * (12.0 / actual_months as f64)
+    } else {
+        0.0
+    };
+
+    Schedule {
+        summary: MortgageSummary {
+            total_interest_paid: total_interest,
+            second_lien_interest_paid: to_f64(second_lien_interest_paid),
+            total_principal_paid: total_principal,
+            total_taxes_paid: total_taxes,
+            total_insurance_paid: total_insurance,
+            total_maintenance_paid: total_maintenance,
+            total_pmi_paid: total_pmi,
+            total_hoa_paid: total_hoa,
+            total_rental_income,
+            total_payments,
+            total_cost_of_capital,
+            total_waste_cost,
+            total_tax_benefit,
+            total_after_tax_waste_cost: total_waste_cost - total_tax_benefit,
+            final_house_value,
+            final_equity,
+            anniversaries,
+            months_to_payoff: actual_months,
+            effective_interest_rate,
+            balloon_payment: to_f64(balloon_payment),
+            balloon_month,
+            pmi_dropoff_month,
+            upfront_program_fee: scenario.upfront_program_fee(),
+            closing_costs: scenario.net_closing_costs(),
+            cash_to_close: scenario.cash_to_close(),
+            points_break_even_month: points_break_even(scenario, &rows),
+        },
+        rows,
+    }
+}
+
+/// First month in which cumulative payment savings against the same loan without points
+/// or credits cover the net cost of the points.
+fn points_break_even(scenario: &Scenario, rows: &[MortgageRow]) -> Option<u32> {
+    let closing = &scenario.closing_costs;
+    let loan_amount = scenario.loan_amount();
+    let net_points_cost = closing.points_cost(loan_amount) - closing.lender_credit(loan_amount);
+    if net_points_cost <= 0.0 {
+        return None;
+    }
+
+    let mut baseline = scenario.clone();
+    baseline.closing_costs.discount_points = 0.0;
+    baseline.closing_costs.lender_credit_points = 0.0;
+    let baseline = simulate(&baseline);
+
+    let mut savings = 0.0;
+    rows.iter().zip(&baseline.rows).find_map(|(row, baseline_row)| {
+        savings += baseline_row.actual_payment - row.actual_payment;
+        (savings >= net_points_cost).then_some(row.month)
+    })
+}
+
+/// Monthly share of an annual cost that is either a rate of home value or a fixed amount
+/// grown by `escalation_factor`.
+fn monthly_home_cost(cost: Cost, house_value: f64, escalation_factor: f64) -> f64 {
+    match cost {
+        Cost::Rate(rate) => house_value * rate / 12.0,
+        Cost::Fixed(amount) => amount * escalation_factor / 12.0,
+    }
+}
+
+impl Schedule {
+    /// Equity position at the end of `month`, or `None` past the end of the schedule.
+    pub fn equity_at(&self, month: u32) -> Option<EquitySnapshot> {
+        let index = month.checked_sub(1)? as usize;
+        self.rows.get(index).map(EquitySnapshot::from)
+    }
+
+    /// Writes the month-by-month rows followed by the summary statistics as CSV.
+    pub fn write_csv<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(out, "Month,Interest,Principal,Extra Principal,Repair Costs,HOA,Taxes,Insurance,PMI,Actual Payment,Cost of Capital,Waste Cost,Cost,Debt,Interest Rate,House Cost,Equity,PMI Dropped,LTV,Combined LTV,Tax Benefit,After-Tax Waste Cost,Assessed Value,Prepayment,Required Payment,Second Lien Interest,Second Lien Principal,Second Lien Debt,Rental Income,Cash Out")?;
+
+        for row in &self.rows {
+            writeln!(
+                out,
+                "{},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.4},{:.2},{:.2},{},{:.4},{:.4},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2}",
+                row.month,
+                row.interest,
+                row.principal,
+                row.extra_principal,
+                row.repair_costs,
+                row.hoa,
+                row.taxes,
+                row.insurance,
+                row.pmi,
+                row.actual_payment,
+                row.cost_of_capital,
+                row.waste_cost,
+                row.cost,
+                row.debt,
+                row.interest_rate,
+                row.house_cost,
+                row.equity,
+                row.pmi_dropoff.map(|reason| reason.to_string()).unwrap_or_default(),
+                row.ltv,
+                row.combined_ltv,
+                row.tax_benefit,
+                row.after_tax_waste_cost,
+                row.assessed_value,
+                row.prepayment,
+                row.required_payment,
+                row.second_lien_interest,
+                row.second_lien_principal,
+                row.second_lien_debt,
+                row.rental_income,
+                row.cash_out
+            )?;
+        }
+
+        let summary = &self.summary;
+        writeln!(out)?;
+        writeln!(out, "Summary Statistics")?;
+        writeln!(out, "Total Interest Paid,{:.2}", summary.total_interest_paid)?;
+        writeln!(out, "Second Lien Interest Paid,{:.2}", summary.second_lien_interest_paid)?;
+        writeln!(out, "Total Principal Paid,{:.2}", summary.total_principal_paid)?;
+        writeln!(out, "Total Taxes Paid,{:.2}", summary.total_taxes_paid)?;
+        writeln!(out, "Total Insurance Paid,{:.2}", summary.total_insurance_paid)?;
+        writeln!(out, "Total Maintenance Paid,{:.2}", summary.total_maintenance_paid)?;
+        writeln!(out, "Total PMI Paid,{:.2}", summary.total_pmi_paid)?;
+        writeln!(out, "Total HOA Paid,{:.2}", summary.total_hoa_paid)?;
+        writeln!(out, "Total Rental Income,{:.2}", summary.total_rental_income)?;
+        writeln!(out, "Total Payments,{:.2}", summary.total_payments)?;
+        writeln!(out, "Total Cost of Capital,{:.2}", summary.total_cost_of_capital)?;
+        writeln!(out, "Total Waste Cost,{:.2}", summary.total_waste_cost)?;
+        writeln!(out, "Total Tax Benefit,{:.2}", summary.total_tax_benefit)?;
+        writeln!(out, "Total After-Tax Waste Cost,{:.2}", summary.total_after_tax_waste_cost)?;
+        writeln!(out, "Final House Value,{:.2}", summary.final_house_value)?;
+        writeln!(out, "Final Equity,{:.2}", summary.final_equity)?;
+        writeln!(out, "Months to Payoff,{}", summary.months_to_payoff)?;
+        writeln!(out, "Effective Interest Rate,{:.4}", summary.effective_interest_rate)?;
+        writeln!(out, "Balloon Payment,{:.2}", summary.balloon_payment)?;
+        writeln!(out, "Upfront Program Fee,{:.2}", summary.upfront_program_fee)?;
+        writeln!(out, "Closing Costs,{:.2}", summary.closing_costs)?;
+        writeln!(out, "Cash to Close,{:.2}", summary.cash_to_close)?;
+        if let Some(month) = summary.points_break_even_month {
+            writeln!(out, "Points Break-Even Month,{}", month)?;
+        }
+        if let Some(month) = summary.pmi_dropoff_month {
+            writeln!(out, "PMI Dropped Off in Month,{}", month)?;
+        }
+
+        writeln!(out)?;
+        writeln!(out, "Year,Month,House Value,Debt,Equity,LTV,Combined LTV")?;
+        for anniversary in &summary.anniversaries {
+            writeln!(
+                out,
+                "{},{},{:.2},{:.2},{:.2},{:.4},{:.4}",
+                anniversary.month / 12,
+                anniversary.month,
+                anniversary.house_value,
+                anniversary.debt,
+                anniversary.equity,
+                anniversary.ltv,
+                anniversary.combined_ltv
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scenario::{Cost, LoanTerm, Scenario};
+use crate::schedule::simulate;
+
+/// Result a sensitivity analysis ranks inputs by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SensitivityMetric {
+    #[default]
+    TotalWasteCost,
+    MonthlyPayment,
+    FinalEquity,
+}
+
+impl SensitivityMetric {
+    pub const ALL: [SensitivityMetric; 3] = [
+        SensitivityMetric::TotalWasteCost,
+        SensitivityMetric::MonthlyPayment,
+        SensitivityMetric::FinalEquity,
+    ];
+}
+
+/// Headline results of one run of the schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SensitivityOutcome {
+    /// Total waste cost after any income tax benefit.
+    pub total_waste_cost: f64,
+    /// First month's actual payment.
+    pub monthly_payment: f64,
+    pub final_equity: f64,
+}
+
+impl SensitivityOutcome {
+    fn of(scenario: &Scenario) -> Self {
+        let schedule = simulate(scenario);
+        Self {
+            total_waste_cost: schedule.summary.total_after_tax_waste_cost,
+            monthly_payment: schedule.rows.first().map_or(0.0, |row| row.actual_payment),
+            final_equity: schedule.summary.final_equity,
+        }
+    }
+
+    pub fn metric(&self, metric: SensitivityMetric) -> f64 {
+        match metric {
+            SensitivityMetric::TotalWasteCost => self.total_waste_cost,
+            SensitivityMetric::MonthlyPayment => self.monthly_payment,
+            SensitivityMetric::FinalEquity => self.final_equity,
+        }
+    }
+}
+
+/// Outcomes with one input lowered and raised by the analysis's change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sensitivity {
+    pub input: String,
+    pub low: SensitivityOutcome,
+    pub high: SensitivityOutcome,
+}
+
+impl Sensitivity {
+    /// Distance between the low and high outcomes for `metric`.
+    pub fn swing(&self, metric: SensitivityMetric) -> f64 {
+        (self.high.metric(metric) - self.low.metric(metric)).abs()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitivityAnalysis {
+    /// Relative change applied to each input, as a fraction of its value.
+    pub change: f64,
+    pub base: SensitivityOutcome,
+    pub inputs: Vec<Sensitivity>,
+}
+
+impl SensitivityAnalysis {
+    /// Inputs ordered from the largest swing in `metric` to the smallest, for a tornado
+    /// chart.
+    pub fn ranked(&self, metric: SensitivityMetric) -> Vec<&Sensitivity> {
+        let mut ranked: Vec<_> = self.inputs.iter().collect();
+        ranked.sort_by(|a, b| b.swing(metric).total_cmp(&a.swing(metric)));
+        ranked
+    }
+}
+
+fn scale_cost(cost: &mut Cost, factor: f64) {
+    match cost {
+        Cost::Rate(value) | Cost::Fixed(value) => *value *= factor,
+    }
+}
+
+/// Scales one input of a scenario by a factor.
+type Scale = fn(&mut Scenario, f64);
+
+const INPUTS: [(&str, Scale); 13] = [
+    ("House Value", |s, factor| s.house_value *= factor),
+    ("Down Payment", |s, factor| scale_cost(&mut s.down_payment, factor)),
+    ("HOA Fee", |s, factor| s.hoa_monthly *= factor),
+    ("Interest Rate", |s, factor| s.interest_rate *= factor),
+    ("Property Tax", |s, factor| scale_cost(&mut s.property_tax, factor)),
+    ("Insurance", |s, factor| scale_cost(&mut s.insurance, factor)),
+    ("Maintenance", |s, factor| scale_cost(&mut s.maintenance, factor)),
+    ("PMI", |s, factor| scale_cost(&mut s.pmi, factor)),
+    ("House Appreciation", |s, factor| s.appreciation_rate *= factor),
+    ("Inflation", |s, factor| s.inflation_rate *= factor),
+    ("Investment Return", |s, factor| s.investment_return *= factor),
+    ("Loan Term", |s, factor| {
+        s.loan_term = match s.loan_term {
+            LoanTerm::Years(years) => LoanTerm::Years(years * factor),
+            LoanTerm::Months(months) => LoanTerm::Months((months as f64 * factor).round() as u32),
+        }
+    }),
+    ("Extra Principal", |s, factor| s.extra_principal *= factor),
+];
+
+/// Reruns `scenario` with each input lowered and raised by `change` (`0.10` for ±10% of
+/// its value). Inputs that are zero, and so cannot move, are left out.
+pub fn analyze_sensitivity(scenario: &Scenario, change: f64) -> SensitivityAnalysis {
+    let base = SensitivityOutcome::of(scenario);
+    let inputs = INPUTS
+        .iter()
+        .filter_map(|&(input, scale)| {
+            let mut low = scenario.clone();
+            scale(&mut low, 1.0 - change);
+            let mut high = scenario.clone();
+            scale(&mut high, 1.0 + change);
+            if low == high {
+                return None;
+            }
+            Some(Sensitivity {
+                input: input.to_string(),
+                low: SensitivityOutcome::of(&low),
+                high: SensitivityOutcome::of(&high),
+            })
+        })
+        .collect();
+
+    SensitivityAnalysis { change, base, inputs }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use crate::schedule::MortgageRow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilingStatus {
+    Single,
+    MarriedFilingJointly,
+    MarriedFilingSeparately,
+    HeadOfHousehold,
+}
+
+/// Federal income tax inputs for valuing the mortgage interest and SALT deductions.
+///
+/// Amounts are annual. [`TaxProfile::new`] fills the standard deduction, SALT cap and
+/// mortgage debt limit with 2026 figures for the filing status; override them as the
+/// law changes or when the SALT cap phases down at higher incomes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxProfile {
+    pub filing_status: FilingStatus,
+    pub marginal_rate: f64,
+    pub standard_deduction: f64,
+    pub salt_cap: f64,
+    /// Acquisition debt whose interest is deductible.
+    pub mortgage_debt_limit: f64,
+    /// State and local income tax, which shares the SALT cap with property tax.
+    pub state_income_tax: f64,
+    /// Itemized deductions unrelated to the home, such as charitable gifts.
+    pub other_itemized: f64,
+}
+
+impl TaxProfile {
+    pub fn new(filing_status: FilingStatus, marginal_rate: f64) -> Self {
+        let (standard_deduction, salt_cap, mortgage_debt_limit) = match filing_status {
+            FilingStatus::Single => (16_100.0, 40_400.0, 750_000.0),
+            FilingStatus::MarriedFilingJointly => (32_200.0, 40_400.0, 750_000.0),
+            FilingStatus::MarriedFilingSeparately => (16_100.0, 20_200.0, 375_000.0),
+            FilingStatus::HeadOfHousehold => (24_150.0, 40_400.0, 750_000.0),
+        };
+        Self {
+            filing_status,
+            marginal_rate,
+            standard_deduction,
+            salt_cap,
+            mortgage_debt_limit,
+            state_income_tax: 0.0,
+            other_itemized: 0.0,
+        }
+    }
+
+    /// Tax saved in one year by itemizing with the home's interest and property tax,
+    /// compared with the deduction the household would take without the home.
+    pub fn annual_benefit(&self, interest: f64, property_taxes: f64, average_balance: f64) -> f64 {
+        let deductible_share = if average_balance > self.mortgage_debt_limit {
+            self.mortgage_debt_limit / average_balance
+        } else {
+            1.0
+        };
+        let with_home = interest * deductible_share
+            + (property_taxes + self.state_income_tax).min(self.salt_cap)
+            + self.other_itemized;
+        let without_home = self.state_income_tax.min(self.salt_cap) + self.other_itemized;
+
+        let deduction_with_home = with_home.max(self.standard_deduction);
+        let deduction_without_home = without_home.max(self.standard_deduction);
+        (deduction_with_home - deduction_without_home) * self.marginal_rate
+    }
+
+    /// Fills each row's `tax_benefit` and `after_tax_waste_cost`, spreading every tax
+    /// year's benefit evenly over its months.
+    pub(crate) fn apply(&self, rows: &mut [MortgageRow]) {
+        for year in rows.chunks_mut(12) {
+            let interest: f64 = year.iter().map(|row| row.interest).sum();
+            let property_taxes: f64 = year.iter().map(|row| row.taxes).sum();
+            let average_balance = year
+                .iter()
+                .map(|row| row.debt + row.principal + row.extra_principal + row.prepayment + row.balloon)
+                .sum::<f64>()
+                / year.len() as f64;
+
+            let monthly_benefit = self.annual_benefit(interest, property_taxes, average_balance) / year.len() as f64;
+            for row in year {
+                row.tax_benefit = monthly_benefit;
+                row.after_tax_waste_cost = row.waste_cost - monthly_benefit;
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{simulate, solve_affordability, AffordabilityInputs, BindingConstraint, Cost, Scenario};
+
+fn borrower(available_cash: f64, monthly_debts: f64) -> AffordabilityInputs {
+    AffordabilityInputs {
+        gross_annual_income: 150_000.0,
+        monthly_debts,
+        available_cash,
+        ..AffordabilityInputs::default()
+    }
+}
+
+#[test]
+fn front_end_ratio_binds_with_plenty_of_cash_and_no_debts() {
+    let affordability = solve_affordability(&Scenario::default(), &borrower(500_000.0, 0.0)).unwrap();
+
+    assert_eq!(affordability.binding, BindingConstraint::FrontEndDti);
+    assert_close(affordability.housing_payment, 3_500.0);
+    assert!((affordability.front_end_ratio - 0.28).abs() < 1e-6);
+
+    // The payment at that price is the same one the schedule charges, taxes and all
+    let schedule = simulate(&Scenario {
+        house_value: affordability.max_price,
+        ..Scenario::default()
+    });
+    let first = &schedule.rows[0];
+    assert_close(first.required_payment + first.taxes + first.insurance + first.hoa + first.pmi, 3_500.0);
+}
+
+#[test]
+fn other_debts_make_the_back_end_ratio_bind() {
+    let affordability = solve_affordability(&Scenario::default(), &borrower(500_000.0, 1_500.0)).unwrap();
+
+    assert_eq!(affordability.binding, BindingConstraint::BackEndDti);
+    assert!((affordability.back_end_ratio - 0.36).abs() < 1e-6);
+    assert!(affordability.front_end_ratio < 0.28);
+}
+
+#[test]
+fn cash_binds_a_percentage_down_payment() {
+    let affordability = solve_affordability(&Scenario::default(), &borrower(60_000.0, 0.0)).unwrap();
+
+    assert_eq!(affordability.binding, BindingConstraint::Cash);
+    assert_close(affordability.max_price, 300_000.0);
+    assert_close(affordability.cash_to_close, 60_000.0);
+}
+
+#[test]
+fn unmeetable_limits_name_the_constraint() {
+    let over_extended = solve_affordability(&Scenario::default(), &borrower(500_000.0, 5_000.0));
+    assert_eq!(over_extended.unwrap_err(), BindingConstraint::BackEndDti);
+
+    let fixed_down = Scenario {
+        down_payment: Cost::Fixed(100_000.0),
+        ..Scenario::default()
+    };
+    let short_on_cash = solve_affordability(&fixed_down, &borrower(50_000.0, 0.0));
+    assert_eq!(short_on_cash.unwrap_err(), BindingConstraint::Cash);
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{simulate, Arm, IndexPath, Scenario};
+
+/// Rates need a tighter tolerance than dollar amounts.
+fn assert_rate(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "expected {expected:.6}, got {actual:.6}");
+}
+
+/// 5/1 ARM at 2.75% over the index with 2/2/5 caps.
+fn five_one(index: f64) -> Arm {
+    Arm::hybrid(5, 12, IndexPath::Constant(index), 0.0275)
+}
+
+#[test]
+fn resets_after_the_fixed_period_and_then_every_adjustment_period() {
+    let arm = five_one(0.045);
+
+    assert!(!arm.is_reset_month(60));
+    assert!(arm.is_reset_month(61));
+    assert!(!arm.is_reset_month(62));
+    assert!(arm.is_reset_month(73));
+}
+
+#[test]
+fn initial_and_periodic_caps_limit_each_reset() {
+    // Fully indexed at 7.25%, but the first reset may only move 2% from the 5% start
+    let arm = five_one(0.045);
+    assert_rate(arm.adjusted_rate(61, 0.05, 0.05), 0.07);
+    assert_rate(arm.adjusted_rate(73, 0.07, 0.05), 0.0725);
+
+    // Six-month resets cap the first move at 5% and later ones at 1%
+    let arm = Arm::hybrid(7, 6, IndexPath::Constant(0.08), 0.0275);
+    assert_rate(arm.adjusted_rate(85, 0.04, 0.04), 0.09);
+    assert_rate(arm.adjusted_rate(91, 0.07, 0.04), 0.08);
+}
+
+#[test]
+fn lifetime_cap_and_floor_bound_the_rate() {
+    let rising = five_one(0.10);
+    assert_rate(rising.adjusted_rate(61, 0.05, 0.05), 0.07);
+    assert_rate(rising.adjusted_rate(73, 0.07, 0.05), 0.09);
+    assert_rate(rising.adjusted_rate(85, 0.09, 0.05), 0.10);
+
+    let falling = Arm {
+        floor: 0.04,
+        ..five_one(0.0)
+    };
+    assert_rate(falling.adjusted_rate(61, 0.05, 0.05), 0.04);
+}
+
+#[test]
+fn schedule_re_amortizes_at_the_reset_rate() {
+    let schedule = simulate(&Scenario {
+        interest_rate: 0.05,
+        arm: Some(five_one(0.045)),
+        ..flat_scenario()
+    });
+
+    assert_rate(schedule.rows[59].interest_rate, 0.05);
+    assert_rate(schedule.rows[60].interest_rate, 0.07);
+    let balance = schedule.rows[59].debt;
+    let payment = balance * (0.07 / 12.0) / (1.0 - (1.0 + 0.07 / 12.0_f64).powi(-300));
+    assert_close(schedule.rows[60].required_payment, (payment * 100.0).round() / 100.0);
+    assert_rate(schedule.rows[72].interest_rate, 0.0725);
+    assert_close(schedule.rows.last().unwrap().debt, 0.0);
+}
+
+#[test]
+fn index_csv_is_read_in_percent_and_sorted_by_month() {
+    let csv = "month,rate\n61,5.25\n\n1,4.5\n";
+    let index = IndexPath::from_csv(csv.as_bytes()).unwrap();
+
+    assert_eq!(index, IndexPath::Stepped(vec![(1, 0.045), (61, 0.0525)]));
+    assert_rate(index.rate_at(60), 0.045);
+    assert_rate(index.rate_at(61), 0.0525);
+    assert_rate(index.rate_at(200), 0.0525);
+
+    assert!(IndexPath::from_csv("1,4.5\nnext,5".as_bytes()).is_err());
+    assert!(IndexPath::from_csv("month,rate\n".as_bytes()).is_err());
+}
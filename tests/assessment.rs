@@ -0,0 +1,66 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{simulate, Assessment, Cost, Scenario};
+
+/// $500k purchase appreciating 6% a year with a 1.2% property tax.
+fn hot_market(assessment: Assessment) -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        appreciation_rate: 0.06,
+        property_tax: Cost::Rate(0.012),
+        assessment,
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn default_assessment_tracks_market_value() {
+    let schedule = simulate(&hot_market(Assessment::default()));
+
+    for row in &schedule.rows {
+        assert_close(row.assessed_value, row.house_cost);
+        assert_close(row.taxes, row.house_cost * 0.012 / 12.0);
+    }
+}
+
+#[test]
+fn capped_assessment_grows_at_most_the_cap_each_anniversary() {
+    let schedule = simulate(&hot_market(Assessment {
+        annual_cap: Some(0.02),
+        ..Assessment::default()
+    }));
+
+    assert_close(schedule.rows[0].assessed_value, 500_000.0);
+    assert_close(schedule.rows[11].assessed_value, 500_000.0);
+    assert_close(schedule.rows[12].assessed_value, 510_000.0);
+    assert_close(schedule.rows[120].assessed_value, 500_000.0 * 1.02_f64.powi(10));
+    assert_close(schedule.rows[120].taxes, 500_000.0 * 1.02_f64.powi(10) * 0.012 / 12.0);
+}
+
+#[test]
+fn reassessment_cycle_holds_value_between_cycles() {
+    let schedule = simulate(&hot_market(Assessment {
+        reassessment_years: Some(3),
+        ..Assessment::default()
+    }));
+
+    assert_close(schedule.rows[24].assessed_value, 500_000.0);
+    assert_close(schedule.rows[36].assessed_value, schedule.rows[36].house_cost);
+    assert_close(schedule.rows[47].assessed_value, schedule.rows[36].house_cost);
+}
+
+#[test]
+fn homestead_exemption_and_senior_freeze() {
+    let schedule = simulate(&hot_market(Assessment {
+        annual_cap: Some(0.02),
+        homestead_exemption: 50_000.0,
+        freeze_year: Some(5),
+        ..Assessment::default()
+    }));
+
+    let frozen = 500_000.0 * 1.02_f64.powi(4);
+    assert_close(schedule.rows[48].assessed_value, frozen);
+    assert_close(schedule.rows[300].assessed_value, frozen);
+    assert_close(schedule.rows[300].taxes, (frozen - 50_000.0) * 0.012 / 12.0);
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{simulate, ClosingCostItem, ClosingCosts, Scenario};
+
+fn items() -> Vec<ClosingCostItem> {
+    vec![
+        ClosingCostItem {
+            name: "Lender Fees".to_string(),
+            amount: 3000.0,
+        },
+        ClosingCostItem {
+            name: "Title".to_string(),
+            amount: 2000.0,
+        },
+    ]
+}
+
+#[test]
+fn cash_to_close_adds_net_closing_costs_to_the_down_payment() {
+    let scenario = Scenario {
+        closing_costs: ClosingCosts {
+            items: items(),
+            discount_points: 1.0,
+            seller_concessions: 1500.0,
+            ..ClosingCosts::default()
+        },
+        ..flat_scenario()
+    };
+
+    // $5,000 itemized plus one point on $400k, less $1,500 from the seller
+    assert_close(scenario.net_closing_costs(), 7500.0);
+    assert_close(scenario.cash_to_close(), 107_500.0);
+    assert!((scenario.note_rate() - 0.0625).abs() < 1e-12);
+    let summary = simulate(&scenario).summary;
+    assert_close(summary.closing_costs, 7500.0);
+    assert_close(summary.cash_to_close, 107_500.0);
+}
+
+#[test]
+fn credits_and_concessions_never_pay_out_cash() {
+    let closing = ClosingCosts {
+        items: items(),
+        lender_credit_points: 1.0,
+        seller_concessions: 2000.0,
+        ..ClosingCosts::default()
+    };
+
+    assert_close(closing.net_cost(400_000.0), 0.0);
+    assert!((closing.rate_adjustment() - 0.0025).abs() < 1e-12);
+}
+
+#[test]
+fn points_break_even_when_payment_savings_repay_them() {
+    let schedule = simulate(&Scenario {
+        closing_costs: ClosingCosts {
+            discount_points: 1.0,
+            ..ClosingCosts::default()
+        },
+        ..flat_scenario()
+    });
+
+    // $4,000 buys the rate down to 6.25%, saving $65.40 a month
+    assert_close(schedule.rows[0].required_payment, 2462.87);
+    assert_eq!(schedule.summary.points_break_even_month, Some(62));
+
+    let no_points = simulate(&flat_scenario());
+    assert_eq!(no_points.summary.points_break_even_month, None);
+}
@@ -0,0 +1,18 @@
+use homebuyer::Scenario;
+
+pub fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 0.01,
+        "expected {expected:.4}, got {actual:.4}"
+    );
+}
+
+/// $500k purchase with 20% down at 6.5% over 30 years and no appreciation.
+#[allow(dead_code)]
+pub fn flat_scenario() -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        appreciation_rate: 0.0,
+        ..Scenario::default()
+    }
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{simulate, Cost, Escalation, Scenario};
+
+/// $500k purchase with a $300 HOA and fixed-dollar tax, insurance and maintenance.
+fn fixed_costs(inflation_rate: f64, escalation: Escalation) -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        hoa_monthly: 300.0,
+        property_tax: Cost::Fixed(6_000.0),
+        insurance: Cost::Fixed(1_800.0),
+        maintenance: Cost::Fixed(3_600.0),
+        inflation_rate,
+        escalation,
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn fixed_lines_step_up_on_anniversaries() {
+    let schedule = simulate(&fixed_costs(0.03, Escalation::default()));
+
+    assert_close(schedule.rows[11].hoa, 300.0);
+    assert_close(schedule.rows[12].hoa, 309.0);
+    assert_close(schedule.rows[12].insurance, 150.0 * 1.03);
+    assert_close(schedule.rows[120].taxes, 500.0 * 1.03_f64.powi(10));
+    assert_close(schedule.rows[120].repair_costs, 300.0 * 1.03_f64.powi(10));
+}
+
+#[test]
+fn line_rates_override_general_inflation() {
+    let schedule = simulate(&fixed_costs(
+        0.03,
+        Escalation {
+            hoa: Some(0.05),
+            insurance: Some(0.0),
+            ..Escalation::default()
+        },
+    ));
+
+    assert_close(schedule.rows[24].hoa, 300.0 * 1.05_f64.powi(2));
+    assert_close(schedule.rows[24].insurance, 150.0);
+    assert_close(schedule.rows[24].taxes, 500.0 * 1.03_f64.powi(2));
+}
+
+#[test]
+fn percentage_lines_are_not_escalated() {
+    let scenario = Scenario {
+        house_value: 500_000.0,
+        appreciation_rate: 0.0,
+        inflation_rate: 0.03,
+        ..Scenario::default()
+    };
+    let schedule = simulate(&scenario);
+
+    assert_close(schedule.rows[120].taxes, schedule.rows[0].taxes);
+}
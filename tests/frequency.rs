@@ -0,0 +1,69 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{compare_frequencies, simulate, PaymentFrequency, Scenario};
+
+#[test]
+fn biweekly_interest_accrues_per_period() {
+    let schedule = simulate(&Scenario {
+        payment_frequency: PaymentFrequency::Biweekly,
+        ..flat_scenario()
+    });
+
+    // Two payments in January: $1,000.00 of interest on the full balance, then interest
+    // on what the first payment left
+    let first = &schedule.rows[0];
+    let payment = first.required_payment / 2.0;
+    let second_interest = ((400_000.0 - (payment - 1000.0)) * 0.065 / 26.0 * 100.0).round() / 100.0;
+    assert_close(first.interest, 1000.0 + second_interest);
+
+    // 26 payments a year land three in some months
+    let payments_per_year: f64 = schedule.rows[..12].iter().map(|row| row.required_payment).sum::<f64>() / payment;
+    assert_close(payments_per_year, 26.0);
+    assert_close(schedule.rows[5].required_payment, 3.0 * payment);
+}
+
+#[test]
+fn every_frequency_pays_off_without_a_balloon() {
+    for payment_frequency in PaymentFrequency::ALL {
+        let schedule = simulate(&Scenario {
+            payment_frequency,
+            ..flat_scenario()
+        });
+
+        assert_eq!(schedule.rows.last().unwrap().debt, 0.0, "{payment_frequency}");
+        assert_eq!(schedule.summary.balloon_payment, 0.0, "{payment_frequency}");
+        assert_close(schedule.summary.total_principal_paid, 400_000.0);
+    }
+}
+
+#[test]
+fn accelerated_biweekly_saves_years_against_monthly() {
+    let outcomes = compare_frequencies(&flat_scenario());
+    let monthly = &outcomes[0];
+    let accelerated = outcomes
+        .iter()
+        .find(|outcome| outcome.frequency == PaymentFrequency::AcceleratedBiweekly)
+        .unwrap();
+
+    assert_eq!(monthly.months_to_payoff, 360);
+    assert_close(monthly.total_interest_paid, 510_179.81);
+    assert_eq!(monthly.months_saved, 0);
+
+    assert_eq!(accelerated.months_to_payoff, 290);
+    assert_eq!(accelerated.months_saved, 70);
+    assert_close(accelerated.interest_saved, monthly.total_interest_paid - accelerated.total_interest_paid);
+    assert!(accelerated.interest_saved > 100_000.0);
+}
+
+#[test]
+fn plain_biweekly_keeps_the_term() {
+    let outcomes = compare_frequencies(&flat_scenario());
+
+    for outcome in &outcomes[1..] {
+        if outcome.frequency != PaymentFrequency::AcceleratedBiweekly {
+            assert_eq!(outcome.months_to_payoff, 360, "{}", outcome.frequency);
+            assert!(outcome.interest_saved > 0.0 && outcome.interest_saved < 1_000.0);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{goal_seek, simulate, Cost, GoalInput, GoalOutput, Scenario};
+
+fn scenario() -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn down_payment_for_a_target_payment() {
+    let found = goal_seek(&scenario(), GoalInput::DownPayment, GoalOutput::FirstPayment, 3_500.0).unwrap();
+
+    let schedule = simulate(&Scenario {
+        down_payment: Cost::Fixed(found.input_value),
+        ..scenario()
+    });
+    assert!((found.achieved - 3_500.0).abs() < 0.05);
+    assert_close(schedule.rows[0].actual_payment, found.achieved);
+}
+
+#[test]
+fn smallest_extra_principal_for_a_payoff_month() {
+    let found = goal_seek(&scenario(), GoalInput::ExtraPrincipal, GoalOutput::PayoffMonth, 240.0).unwrap();
+    assert_eq!(found.achieved, 240.0);
+
+    let payoff = |extra_principal: f64| {
+        simulate(&Scenario {
+            extra_principal,
+            ..scenario()
+        })
+        .summary
+        .months_to_payoff
+    };
+    assert_eq!(payoff(found.input_value), 240);
+    assert!(payoff(found.input_value - 0.05) > 240);
+}
+
+#[test]
+fn rate_for_a_target_waste_cost() {
+    let target = simulate(&Scenario {
+        interest_rate: 0.055,
+        ..scenario()
+    })
+    .summary
+    .total_after_tax_waste_cost;
+
+    let found = goal_seek(&scenario(), GoalInput::InterestRate, GoalOutput::TotalWasteCost, target).unwrap();
+    assert!((found.input_value - 0.055).abs() < 1e-5);
+}
+
+#[test]
+fn unreachable_targets_report_the_reachable_range() {
+    let error = goal_seek(&scenario(), GoalInput::InterestRate, GoalOutput::FirstPayment, 100.0).unwrap_err();
+
+    assert!(error.lowest_input_gives > 100.0);
+    assert!(error.highest_input_gives > error.lowest_input_gives);
+    assert!(error.to_string().starts_with("no interest rate reaches a first month's payment of 100.00"));
+}
+
+#[test]
+fn equity_at_a_year_by_price() {
+    let found = goal_seek(&scenario(), GoalInput::Price, GoalOutput::EquityAtYear(10), 250_000.0).unwrap();
+    let schedule = simulate(&Scenario {
+        house_value: found.input_value,
+        ..scenario()
+    });
+
+    assert!((schedule.equity_at(120).unwrap().equity - 250_000.0).abs() < 1.0);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{simulate, Cost, IndexPath, LoanTerm, Scenario, SecondLien, SecondLienKind};
+
+/// $500k purchase at 6.5% over 30 years with no appreciation, financed 80/10/10 with a
+/// 15-year fixed second at 8%.
+fn piggyback_scenario() -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        down_payment: Cost::Rate(0.10),
+        appreciation_rate: 0.0,
+        second_lien: Some(SecondLien {
+            amount: Cost::Rate(0.10),
+            term: LoanTerm::Years(15.0),
+            kind: SecondLienKind::Fixed { rate: 0.08 },
+        }),
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn piggyback_second_avoids_pmi_and_adds_its_payment() {
+    let scenario = piggyback_scenario();
+    assert_close(scenario.loan_amount(), 400_000.0);
+    let schedule = simulate(&scenario);
+
+    // $2,528.27 on the $400k first plus $477.83 on the $50k second
+    let first = &schedule.rows[0];
+    assert_close(first.required_payment, 2528.27 + 477.83);
+    assert_close(first.second_lien_interest, 333.33);
+    assert_close(first.interest, 2166.67 + 333.33);
+    assert_close(first.pmi, 0.0);
+    assert_close(first.ltv * 500_000.0 + first.second_lien_debt, first.debt);
+    assert_close(first.combined_ltv, first.debt / 500_000.0);
+    assert_close(first.equity, 500_000.0 - first.debt);
+}
+
+#[test]
+fn second_lien_pays_off_on_its_own_term() {
+    let schedule = simulate(&piggyback_scenario());
+
+    assert_close(schedule.rows[179].second_lien_debt, 0.0);
+    assert_close(schedule.rows[180].second_lien_interest + schedule.rows[180].second_lien_principal, 0.0);
+    assert_close(schedule.rows[180].required_payment, 2528.27);
+    assert_eq!(schedule.summary.months_to_payoff, 360);
+
+    let second_interest: f64 = schedule.rows.iter().map(|row| row.second_lien_interest).sum();
+    assert_close(schedule.summary.second_lien_interest_paid, second_interest);
+    assert!(schedule.summary.second_lien_interest_paid < schedule.summary.total_interest_paid);
+}
+
+#[test]
+fn heloc_is_interest_only_through_the_draw_then_amortizes() {
+    let schedule = simulate(&Scenario {
+        second_lien: Some(SecondLien {
+            amount: Cost::Fixed(50_000.0),
+            term: LoanTerm::Years(30.0),
+            kind: SecondLienKind::Heloc {
+                index: IndexPath::Stepped(vec![(1, 0.075), (61, 0.085)]),
+                margin: 0.005,
+                draw_months: 120,
+            },
+        }),
+        ..piggyback_scenario()
+    });
+
+    // 8% for five years, then 9%, on an untouched balance
+    assert_close(schedule.rows[0].second_lien_interest, 333.33);
+    assert_close(schedule.rows[0].second_lien_principal, 0.0);
+    assert_close(schedule.rows[60].second_lien_interest, 375.0);
+    assert_close(schedule.rows[60].second_lien_rate, 0.09);
+    assert_close(schedule.rows[119].second_lien_debt, 50_000.0);
+
+    // $50k at 9% over the remaining 20 years
+    assert_close(schedule.rows[120].second_lien_interest + schedule.rows[120].second_lien_principal, 449.86);
+    assert_close(schedule.rows.last().unwrap().second_lien_debt, 0.0);
+}
+
+#[test]
+fn second_lien_outlasting_the_first_extends_the_schedule() {
+    let schedule = simulate(&Scenario {
+        loan_term: LoanTerm::Years(15.0),
+        second_lien: Some(SecondLien {
+            amount: Cost::Rate(0.10),
+            term: LoanTerm::Years(20.0),
+            kind: SecondLienKind::Fixed { rate: 0.08 },
+        }),
+        ..piggyback_scenario()
+    });
+
+    assert_eq!(schedule.rows.len(), 240);
+    assert_eq!(schedule.summary.months_to_payoff, 240);
+    let after_first = &schedule.rows[180];
+    assert_close(after_first.interest, after_first.second_lien_interest);
+    assert_close(after_first.ltv, 0.0);
+    assert_close(schedule.summary.final_equity, 500_000.0);
+}
+
+#[test]
+fn heloc_still_drawing_at_the_end_of_its_term_leaves_a_balloon() {
+    let schedule = simulate(&Scenario {
+        second_lien: Some(SecondLien {
+            amount: Cost::Fixed(50_000.0),
+            term: LoanTerm::Years(10.0),
+            kind: SecondLienKind::Heloc {
+                index: IndexPath::Constant(0.075),
+                margin: 0.005,
+                draw_months: 120,
+            },
+        }),
+        ..piggyback_scenario()
+    });
+
+    let last = &schedule.rows[119];
+    assert_close(last.second_lien_interest, 333.33);
+    assert_close(last.second_lien_principal, 0.0);
+    assert_close(last.balloon, 50_000.0);
+    assert_close(last.second_lien_debt, 0.0);
+    assert_close(schedule.summary.balloon_payment, 50_000.0);
+    assert_eq!(schedule.summary.balloon_month, Some(120));
+}
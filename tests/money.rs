@@ -0,0 +1,39 @@
+use homebuyer::{simulate, Cost, Rounding, Scenario};
+
+/// $100,001 loan at 6%, whose first month of interest is exactly $500.005.
+fn half_cent_interest(rounding: Rounding) -> Scenario {
+    Scenario {
+        house_value: 125_001.0,
+        down_payment: Cost::Fixed(25_000.0),
+        interest_rate: 0.06,
+        rounding,
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn half_cents_round_by_the_chosen_rule() {
+    assert_eq!(simulate(&half_cent_interest(Rounding::HalfUp)).rows[0].interest, 500.01);
+    assert_eq!(simulate(&half_cent_interest(Rounding::HalfEven)).rows[0].interest, 500.0);
+}
+
+#[test]
+fn every_amount_is_whole_cents_and_the_balance_ends_at_zero() {
+    let schedule = simulate(&Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    });
+    let is_cents = |amount: f64| ((amount * 100.0).round() - amount * 100.0).abs() < 1e-6;
+
+    for row in &schedule.rows {
+        assert!(is_cents(row.interest) && is_cents(row.principal) && is_cents(row.debt));
+    }
+
+    let regular = &schedule.rows[0];
+    let last = schedule.rows.last().unwrap();
+    let before_last = &schedule.rows[schedule.rows.len() - 2];
+    assert_eq!(last.debt, 0.0);
+    assert_eq!(last.principal, before_last.debt);
+    assert_eq!(last.balloon, 0.0);
+    assert!((last.interest + last.principal - (regular.interest + regular.principal)).abs() < 5.0);
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{run_monte_carlo, simulate, Arm, Cost, IndexPath, MonteCarloAssumptions, RentScenario, Scenario};
+
+fn scenario() -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    }
+}
+
+fn assumptions(scenario: &Scenario) -> MonteCarloAssumptions {
+    MonteCarloAssumptions {
+        paths: 200,
+        ..MonteCarloAssumptions::for_scenario(scenario)
+    }
+}
+
+#[test]
+fn without_volatility_every_path_matches_the_deterministic_schedule() {
+    let scenario = scenario();
+    let mut assumptions = assumptions(&scenario);
+    assumptions.paths = 5;
+    assumptions.appreciation.volatility = 0.0;
+    assumptions.investment_return.volatility = 0.0;
+
+    let result = run_monte_carlo(&scenario, &assumptions).unwrap();
+    let summary = simulate(&scenario).summary;
+
+    assert_close(result.final_equity.p10, summary.final_equity);
+    assert_close(result.final_equity.p90, summary.final_equity);
+    assert_close(result.waste_cost.p50, summary.total_after_tax_waste_cost);
+    assert_close(result.net_worth.p50, summary.final_equity);
+    assert!(result.renter_net_worth.is_none());
+}
+
+#[test]
+fn bands_are_ordered_and_reproducible_from_the_seed() {
+    let scenario = scenario();
+    let assumptions = assumptions(&scenario);
+
+    let first = run_monte_carlo(&scenario, &assumptions).unwrap();
+    let second = run_monte_carlo(&scenario, &assumptions).unwrap();
+
+    for band in [first.final_equity, first.waste_cost, first.net_worth] {
+        assert!(band.p10 < band.p50 && band.p50 < band.p90);
+    }
+    assert_eq!(first.final_equity, second.final_equity);
+    assert_eq!(first.net_worth, second.net_worth);
+}
+
+#[test]
+fn renting_alternative_reports_both_sides() {
+    let scenario = scenario();
+    let mut assumptions = assumptions(&scenario);
+    assumptions.rent = Some(RentScenario {
+        monthly_rent: 2_500.0,
+        rent_growth: 0.03,
+        renters_insurance_monthly: 15.0,
+        deposit: 2_500.0,
+    });
+
+    let result = run_monte_carlo(&scenario, &assumptions).unwrap();
+    let renter = result.renter_net_worth.unwrap();
+
+    assert!(renter.p10 < renter.p90);
+    assert!(result.net_worth.p50 >= result.final_equity.p50);
+}
+
+#[test]
+fn index_volatility_spreads_arm_outcomes() {
+    let scenario = Scenario {
+        arm: Some(Arm::hybrid(5, 12, IndexPath::Constant(0.04), 0.0275)),
+        ..scenario()
+    };
+    let mut assumptions = assumptions(&scenario);
+    assumptions.appreciation.volatility = 0.0;
+    assumptions.investment_return.volatility = 0.0;
+
+    let result = run_monte_carlo(&scenario, &assumptions).unwrap();
+    assert!(result.waste_cost.p90 - result.waste_cost.p10 > 1_000.0);
+}
+
+#[test]
+fn inconsistent_correlations_are_rejected() {
+    let scenario = scenario();
+    let mut assumptions = assumptions(&scenario);
+    assumptions.appreciation_return_correlation = 0.9;
+    assumptions.appreciation_index_correlation = 0.9;
+    assumptions.return_index_correlation = -0.9;
+
+    assert!(run_monte_carlo(&scenario, &assumptions).is_none());
+}
+
+#[test]
+fn paying_all_cash_still_compares_against_renting() {
+    let scenario = Scenario {
+        down_payment: Cost::Rate(1.0),
+        appreciation_rate: 0.0,
+        ..scenario()
+    };
+    let mut assumptions = assumptions(&scenario);
+    assumptions.paths = 5;
+    assumptions.rent = Some(RentScenario {
+        monthly_rent: 2500.0,
+        rent_growth: 0.03,
+        renters_insurance_monthly: 15.0,
+        deposit: 2500.0,
+    });
+
+    let result = run_monte_carlo(&scenario, &assumptions).unwrap();
+    assert_close(result.net_worth.p50, 500_000.0);
+    assert_close(result.renter_net_worth.unwrap().p50, scenario.cash_to_close());
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{simulate, Cost, PmiCancellation, PmiDropOff, Scenario};
+
+/// $500k purchase with 10% down, so PMI is charged on the $450k loan.
+fn ten_percent_down() -> Scenario {
+    Scenario {
+        down_payment: Cost::Rate(0.10),
+        ..flat_scenario()
+    }
+}
+
+fn automatic_only() -> PmiCancellation {
+    PmiCancellation {
+        borrower_request: false,
+        new_appraisal: false,
+    }
+}
+
+#[test]
+fn borrower_request_cancels_at_80_percent_of_the_original_value() {
+    let schedule = simulate(&ten_percent_down());
+
+    // 0.5% a year on the $450k balance to start
+    assert_close(schedule.rows[0].pmi, 187.5);
+
+    // The balance first reaches $400k after month 95, so month 96 is the first without PMI
+    assert!(schedule.rows[93].debt > 400_000.0);
+    assert!(schedule.rows[94].debt <= 400_000.0);
+    assert!(schedule.rows[94].pmi > 0.0);
+    assert_close(schedule.rows[95].pmi, 0.0);
+    assert_eq!(schedule.rows[95].pmi_dropoff, Some(PmiDropOff::BorrowerRequest));
+    assert_eq!(schedule.summary.pmi_dropoff_month, Some(96));
+    let total: f64 = schedule.rows.iter().map(|row| row.pmi).sum();
+    assert_close(schedule.summary.total_pmi_paid, total);
+}
+
+#[test]
+fn automatic_termination_follows_the_original_schedule_at_78_percent() {
+    let schedule = simulate(&Scenario {
+        pmi_cancellation: automatic_only(),
+        ..ten_percent_down()
+    });
+    assert_eq!(schedule.summary.pmi_dropoff_month, Some(110));
+    assert_eq!(schedule.rows[109].pmi_dropoff, Some(PmiDropOff::Automatic));
+
+    // Extra principal hastens a borrower request but not the scheduled termination
+    let prepaid = simulate(&Scenario {
+        extra_principal: 500.0,
+        pmi_cancellation: automatic_only(),
+        ..ten_percent_down()
+    });
+    assert_eq!(prepaid.summary.pmi_dropoff_month, Some(110));
+    let requested = simulate(&Scenario {
+        extra_principal: 500.0,
+        ..ten_percent_down()
+    });
+    assert_eq!(requested.summary.pmi_dropoff_month, Some(50));
+}
+
+#[test]
+fn new_appraisal_cancels_on_appreciated_value_after_seasoning() {
+    let schedule = simulate(&Scenario {
+        appreciation_rate: 0.06,
+        pmi_cancellation: PmiCancellation {
+            borrower_request: true,
+            new_appraisal: true,
+        },
+        ..ten_percent_down()
+    });
+
+    assert_eq!(schedule.summary.pmi_dropoff_month, Some(32));
+    assert_eq!(schedule.rows[31].pmi_dropoff, Some(PmiDropOff::Appraisal));
+    assert!(schedule.rows[30].debt / schedule.rows[30].house_cost <= 0.75);
+}
+
+#[test]
+fn final_termination_at_the_midpoint_of_the_term() {
+    // Twenty years of interest-only payments keep the scheduled balance above 78%
+    let schedule = simulate(&Scenario {
+        interest_only_months: 240,
+        ..ten_percent_down()
+    });
+
+    assert_eq!(schedule.summary.pmi_dropoff_month, Some(181));
+    assert_eq!(schedule.rows[180].pmi_dropoff, Some(PmiDropOff::Midpoint));
+    assert_close(schedule.summary.total_pmi_paid, 180.0 * 187.5);
+}
+
+#[test]
+fn no_pmi_with_20_percent_down() {
+    let schedule = simulate(&flat_scenario());
+    assert_close(schedule.summary.total_pmi_paid, 0.0);
+    assert_eq!(schedule.summary.pmi_dropoff_month, None);
+}
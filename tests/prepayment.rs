@@ -0,0 +1,65 @@
+use homebuyer::{compare_prepayments, simulate, Prepayment, Scenario};
+
+/// $400k loan at 6.5% over 30 years with a $20k bonus in month 18 and $1k every April
+/// (month 4 of a January closing).
+fn with_prepayments() -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        prepayments: vec![Prepayment::once(18, 20_000.0), Prepayment::recurring(4, 1_000.0, 12)],
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn prepayments_land_in_their_months() {
+    let schedule = simulate(&with_prepayments());
+    let months: Vec<u32> = schedule.rows.iter().filter(|row| row.prepayment > 0.0).map(|row| row.month).take(4).collect();
+
+    assert_eq!(months, [4, 16, 18, 28]);
+    assert_eq!(schedule.rows[17].prepayment, 20_000.0);
+    let expected_debt = schedule.rows[16].debt - schedule.rows[17].principal - 20_000.0;
+    assert!((schedule.rows[17].debt - expected_debt).abs() < 0.005);
+}
+
+#[test]
+fn shortening_keeps_the_payment_and_recasting_keeps_the_term() {
+    let comparison = compare_prepayments(&with_prepayments());
+    let baseline = &comparison.without_prepayments;
+
+    assert_eq!(baseline.months_to_payoff, 360);
+    assert!(comparison.shorten_term.months_to_payoff < 300);
+    assert_eq!(comparison.shorten_term.final_required_payment, baseline.final_required_payment);
+
+    assert_eq!(comparison.lower_payment.months_to_payoff, 360);
+    assert!(comparison.lower_payment.final_required_payment < baseline.final_required_payment - 500.0);
+
+    assert!(comparison.shorten_term.total_interest_paid < comparison.lower_payment.total_interest_paid);
+    assert!(comparison.lower_payment.total_interest_paid < baseline.total_interest_paid);
+}
+
+#[test]
+fn recast_lowers_the_payment_only_after_a_prepayment() {
+    let schedule = simulate(&Scenario {
+        recast: true,
+        ..with_prepayments()
+    });
+
+    assert_eq!(schedule.rows[16].required_payment, schedule.rows[17].required_payment);
+    assert!(schedule.rows[18].required_payment < schedule.rows[17].required_payment);
+    assert_eq!(schedule.rows.last().unwrap().debt, 0.0);
+}
+
+#[test]
+fn prepayments_read_from_csv() {
+    let csv = "month,amount,every\n18,20000\n4,1000,12\n";
+    let prepayments = Prepayment::from_csv(csv.as_bytes()).unwrap();
+
+    assert_eq!(prepayments, [Prepayment::once(18, 20_000.0), Prepayment::recurring(4, 1_000.0, 12)]);
+    assert!(Prepayment::from_csv("18,20000\nsoon,5\n".as_bytes()).is_err());
+}
+
+#[test]
+fn malformed_repeat_interval_is_an_error() {
+    let error = Prepayment::from_csv("month,amount,every\n18,20000,abc\n".as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{simulate, Cost, LoanProgram, PmiDropOff, Scenario};
+
+fn program(loan_program: LoanProgram, down_payment: f64) -> Scenario {
+    Scenario {
+        house_value: 400_000.0,
+        down_payment: Cost::Rate(down_payment),
+        loan_program,
+        ..flat_scenario()
+    }
+}
+
+#[test]
+fn fha_under_ten_percent_down_pays_mip_for_the_life_of_the_loan() {
+    let scenario = program(LoanProgram::Fha, 0.035);
+    let schedule = simulate(&scenario);
+
+    // 1.75% up-front MIP on the $386k base loan is financed
+    assert_close(scenario.upfront_program_fee(), 6755.0);
+    assert_close(scenario.loan_amount(), 392_755.0);
+    assert_close(schedule.summary.upfront_program_fee, 6755.0);
+
+    // 0.55% annual MIP above 95% LTV, on the balance, until payoff
+    assert_close(schedule.rows[0].pmi, 392_755.0 * 0.0055 / 12.0);
+    assert!(schedule.rows[359].pmi > 0.0);
+    assert_eq!(schedule.summary.pmi_dropoff_month, None);
+}
+
+#[test]
+fn fha_with_ten_percent_down_ends_mip_after_eleven_years() {
+    let schedule = simulate(&program(LoanProgram::Fha, 0.10));
+
+    assert_close(schedule.rows[0].pmi, 360_000.0 * 1.0175 * 0.0050 / 12.0);
+    assert!(schedule.rows[131].pmi > 0.0);
+    assert_close(schedule.rows[132].pmi, 0.0);
+    assert_eq!(schedule.rows[132].pmi_dropoff, Some(PmiDropOff::ProgramTerm));
+    assert_eq!(schedule.summary.pmi_dropoff_month, Some(133));
+}
+
+#[test]
+fn va_funding_fee_depends_on_down_payment_and_prior_use() {
+    let first_use = LoanProgram::Va {
+        subsequent_use: false,
+        exempt: false,
+    };
+    let subsequent_use = LoanProgram::Va {
+        subsequent_use: true,
+        exempt: false,
+    };
+    let exempt = LoanProgram::Va {
+        subsequent_use: true,
+        exempt: true,
+    };
+
+    assert_close(program(first_use, 0.0).upfront_program_fee(), 400_000.0 * 0.0215);
+    assert_close(program(subsequent_use, 0.0).upfront_program_fee(), 400_000.0 * 0.033);
+    assert_close(program(subsequent_use, 0.05).upfront_program_fee(), 380_000.0 * 0.015);
+    assert_close(program(first_use, 0.10).upfront_program_fee(), 360_000.0 * 0.0125);
+    assert_close(program(exempt, 0.0).upfront_program_fee(), 0.0);
+
+    // No mortgage insurance even with nothing down
+    assert_close(simulate(&program(first_use, 0.0)).summary.total_pmi_paid, 0.0);
+}
+
+#[test]
+fn usda_charges_a_guarantee_fee_and_an_annual_fee_for_life() {
+    let scenario = program(LoanProgram::Usda, 0.0);
+    let schedule = simulate(&scenario);
+
+    assert_close(scenario.loan_amount(), 404_000.0);
+    assert_close(schedule.rows[0].pmi, 404_000.0 * 0.0035 / 12.0);
+    assert!(schedule.rows[359].pmi > 0.0);
+    assert_eq!(schedule.summary.pmi_dropoff_month, None);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{simulate, Scenario};
+
+fn schedule() -> homebuyer::Schedule {
+    simulate(&Scenario {
+        house_value: 500_000.0,
+        inflation_rate: 0.03,
+        ..Scenario::default()
+    })
+}
+
+#[test]
+fn rows_are_deflated_to_the_purchase_date() {
+    let nominal = schedule();
+    let real = nominal.in_real_dollars(0.03);
+
+    let year_25 = &nominal.rows[299];
+    let deflated = &real.rows[299];
+    let factor = 1.03_f64.powf(-300.0 / 12.0);
+    assert_close(deflated.actual_payment, year_25.actual_payment * factor);
+    assert_close(deflated.equity, year_25.equity * factor);
+    assert_close(deflated.debt, year_25.debt * factor);
+    assert_eq!(deflated.interest_rate, year_25.interest_rate);
+    assert_eq!(deflated.ltv, year_25.ltv);
+}
+
+#[test]
+fn summary_totals_add_up_the_deflated_rows() {
+    let nominal = schedule();
+    let real = nominal.in_real_dollars(0.03);
+
+    let interest: f64 = real.rows.iter().map(|row| row.interest).sum();
+    assert_close(real.summary.total_interest_paid, interest);
+    assert!(real.summary.total_interest_paid < nominal.summary.total_interest_paid);
+    assert_close(real.summary.final_equity, real.rows.last().unwrap().equity);
+    assert_close(real.summary.anniversaries[9].equity, real.rows[119].equity);
+    assert_eq!(real.summary.months_to_payoff, nominal.summary.months_to_payoff);
+    assert_close(real.summary.cash_to_close, nominal.summary.cash_to_close);
+}
+
+#[test]
+fn zero_inflation_changes_nothing() {
+    let nominal = schedule();
+    let real = nominal.in_real_dollars(0.0);
+
+    assert_close(real.summary.total_payments, nominal.summary.total_payments);
+    assert_close(real.summary.total_after_tax_waste_cost, nominal.summary.total_after_tax_waste_cost);
+}
+
+#[test]
+fn present_values_discount_each_month() {
+    let nominal = schedule();
+    let present = nominal.present_values(0.05);
+
+    let expected: f64 = nominal
+        .rows
+        .iter()
+        .map(|row| row.actual_payment / 1.05_f64.powf(row.month as f64 / 12.0))
+        .sum();
+    assert_close(present.total_payments, expected);
+    assert!(present.total_waste_cost < nominal.summary.total_waste_cost);
+    assert_close(nominal.present_values(0.0).total_interest_paid, nominal.summary.total_interest_paid);
+}
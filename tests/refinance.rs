@@ -0,0 +1,148 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{
+    analyze_refinance, analyze_sale, simulate, Cost, LoanTerm, PmiDropOff, Refinance, SaleAssumptions, Scenario,
+    Schedule,
+};
+
+/// $500k purchase with 20% down at 7% over 30 years and no appreciation, refinanced to
+/// a new 30-year loan at 5.5% in year 4 for $6,000.
+fn refinance_scenario() -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        interest_rate: 0.07,
+        appreciation_rate: 0.0,
+        refinance: Some(Refinance {
+            month: 37,
+            rate: 0.055,
+            term: LoanTerm::Years(30.0),
+            closing_costs: 6000.0,
+            cash_out: 0.0,
+        }),
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn refinance_continues_with_the_new_loan() {
+    let schedule = simulate(&refinance_scenario());
+
+    // $2,661.21 on the original $400k at 7%, then the remaining balance at 5.5% over 30
+    // more years
+    assert_close(schedule.rows[35].required_payment, 2661.21);
+    let balance = schedule.rows[35].debt;
+    let new_payment = balance * (0.055 / 12.0) / (1.0 - (1.0 + 0.055 / 12.0_f64).powi(-360));
+    assert_close(schedule.rows[36].required_payment, (new_payment * 100.0).round() / 100.0);
+    assert_close(schedule.rows[36].interest, (balance * 0.055 / 12.0 * 100.0).round() / 100.0);
+    assert_close(schedule.rows[36].interest_rate, 0.055);
+    assert_close(schedule.rows[36].refinance_costs, 6000.0);
+
+    assert_eq!(schedule.rows.len(), 36 + 360);
+    assert_close(schedule.rows.last().unwrap().debt, 0.0);
+    assert_close(schedule.summary.balloon_payment, 0.0);
+}
+
+#[test]
+fn break_even_comes_when_savings_cover_closing_costs() {
+    let analysis = analyze_refinance(&refinance_scenario()).unwrap();
+    assert_eq!(analysis.month, 37);
+    assert_close(analysis.old_payment, 2661.21);
+
+    // About $464 a month saved covers $6,000 in the 13th month on the new loan
+    let saved = analysis.old_payment - analysis.new_payment;
+    assert!((464.0..465.0).contains(&saved), "saved {saved}");
+    assert_eq!(analysis.break_even_month, Some(49));
+    assert_close(analysis.new_loan_amount, analysis.payoff_balance);
+    assert!(analysis.interest_saved > 0.0);
+    assert!(analysis.waste_cost_saved > 0.0);
+}
+
+#[test]
+fn cash_out_is_added_to_the_new_loan_and_never_breaks_even_at_the_same_rate() {
+    let mut scenario = refinance_scenario();
+    scenario.interest_rate = 0.055;
+    if let Some(refinance) = &mut scenario.refinance {
+        refinance.cash_out = 50_000.0;
+    }
+    let schedule = simulate(&scenario);
+    let analysis = analyze_refinance(&scenario).unwrap();
+
+    assert_close(analysis.new_loan_amount, analysis.payoff_balance + 50_000.0);
+    assert_close(schedule.rows[36].debt + schedule.rows[36].principal, analysis.new_loan_amount);
+    assert!(analysis.new_payment > analysis.old_payment);
+    assert_eq!(analysis.break_even_month, None);
+}
+
+#[test]
+fn refinance_below_80_percent_ends_pmi() {
+    let schedule = simulate(&Scenario {
+        down_payment: Cost::Rate(0.10),
+        appreciation_rate: 0.05,
+        ..refinance_scenario()
+    });
+
+    assert!(schedule.rows[35].pmi > 0.0);
+    assert_eq!(schedule.rows[36].pmi_dropoff, Some(PmiDropOff::Refinance));
+    assert_close(schedule.rows[36].pmi, 0.0);
+    assert_eq!(schedule.summary.pmi_dropoff_month, Some(37));
+}
+
+#[test]
+fn no_analysis_when_the_loan_is_gone_before_the_refinance() {
+    let scenario = Scenario {
+        loan_term: LoanTerm::Years(2.0),
+        ..refinance_scenario()
+    };
+    assert!(analyze_refinance(&scenario).is_none());
+    assert_eq!(simulate(&scenario).rows.len(), 24);
+}
+
+#[test]
+fn cash_out_is_received_in_the_refinance_month() {
+    let mut scenario = refinance_scenario();
+    scenario.interest_rate = 0.055;
+    let without_cash = simulate(&scenario);
+    if let Some(refinance) = &mut scenario.refinance {
+        refinance.cash_out = 50_000.0;
+    }
+    let schedule = simulate(&scenario);
+
+    let month = &schedule.rows[36];
+    let kept = &without_cash.rows[36];
+    assert_close(month.cash_out, 50_000.0);
+    // Borrowing above 80% of the home's value brings back PMI on the new loan
+    assert!(month.pmi > 0.0);
+    assert_close(
+        month.actual_payment,
+        kept.actual_payment + month.required_payment - kept.required_payment + month.pmi - 50_000.0,
+    );
+    assert_close(
+        month.waste_cost - month.interest - month.pmi - month.cost_of_capital,
+        kept.waste_cost - kept.interest - kept.cost_of_capital,
+    );
+
+    // Over seven years the cash offsets the larger balance owed at sale, leaving only the
+    // extra interest and PMI as a cost
+    let sale = SaleAssumptions::default();
+    let with_cash = analyze_sale(&scenario, &schedule, &sale).unwrap();
+    let kept_cash = analyze_sale(&scenario, &without_cash, &sale).unwrap();
+    let borrowing_cost =
+        |schedule: &Schedule| -> f64 { schedule.rows[..84].iter().map(|row| row.interest + row.pmi).sum() };
+    let extra_cost = borrowing_cost(&schedule) - borrowing_cost(&without_cash);
+    assert!(extra_cost > 0.0);
+    assert_close(with_cash.total_return, kept_cash.total_return - extra_cost);
+}
+
+#[test]
+fn zero_term_refinance_pays_off_in_the_refinance_month() {
+    let mut scenario = refinance_scenario();
+    if let Some(refinance) = &mut scenario.refinance {
+        refinance.term = LoanTerm::Years(0.0);
+    }
+    let schedule = simulate(&scenario);
+
+    assert_eq!(schedule.rows.len(), 37);
+    assert_close(schedule.rows[36].debt, 0.0);
+    assert_close(schedule.rows[36].principal, schedule.rows[35].debt);
+}
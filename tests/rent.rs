@@ -0,0 +1,60 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{compare_rent, simulate, ClosingCostItem, ClosingCosts, RentScenario, Scenario};
+
+fn rent(monthly_rent: f64) -> RentScenario {
+    RentScenario {
+        monthly_rent,
+        rent_growth: 0.03,
+        renters_insurance_monthly: 15.0,
+        deposit: 3000.0,
+    }
+}
+
+#[test]
+fn renter_invests_the_cash_to_close_and_the_monthly_savings() {
+    let scenario = flat_scenario();
+    let buy = simulate(&scenario).rows[0].clone();
+    let comparison = compare_rent(&scenario, &rent(2500.0));
+
+    let first = &comparison.rows[0];
+    assert_close(first.rent_cost, 2515.0);
+    assert_close(first.buy_waste_cost, buy.after_tax_waste_cost);
+    let invested = (100_000.0 - 3000.0) * (1.0 + 0.07 / 12.0) + buy.actual_payment - 2515.0;
+    assert_close(first.renter_net_worth, invested + 3000.0);
+    assert_close(first.buyer_net_worth, buy.equity);
+
+    // Rent steps up on each lease anniversary
+    assert_close(comparison.rows[11].rent, 2500.0);
+    assert_close(comparison.rows[12].rent, 2575.0);
+}
+
+#[test]
+fn break_even_is_the_first_month_buying_stays_ahead() {
+    // $15,000 of closing costs put the buyer behind until appreciation catches up
+    let scenario = Scenario {
+        house_value: 500_000.0,
+        closing_costs: ClosingCosts {
+            items: vec![ClosingCostItem {
+                name: "Title".to_string(),
+                amount: 15_000.0,
+            }],
+            ..ClosingCosts::default()
+        },
+        ..Scenario::default()
+    };
+    let comparison = compare_rent(&scenario, &rent(3500.0));
+    assert_eq!(comparison.break_even_month, Some(28));
+
+    let index = 27;
+    let behind = &comparison.rows[index - 1];
+    assert!(behind.buyer_net_worth < behind.renter_net_worth);
+    assert!(comparison.rows[index..].iter().all(|row| row.buyer_net_worth >= row.renter_net_worth));
+}
+
+#[test]
+fn no_break_even_when_renting_stays_ahead() {
+    let comparison = compare_rent(&flat_scenario(), &rent(1500.0));
+    assert_eq!(comparison.break_even_month, None);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{simulate, RentalUnit, Scenario};
+
+/// The other side of a duplex at $2,000 a month.
+fn duplex_unit() -> RentalUnit {
+    RentalUnit {
+        monthly_rent: 2000.0,
+        vacancy_rate: 0.05,
+        rent_growth: 0.03,
+        management_fee: 0.08,
+        extra_repairs: 100.0,
+    }
+}
+
+#[test]
+fn net_rent_offsets_the_payment_and_waste_cost() {
+    let owner_only = simulate(&flat_scenario());
+    let duplex = simulate(&Scenario {
+        rental_units: vec![duplex_unit()],
+        ..flat_scenario()
+    });
+
+    // $1,900 collected after vacancy, $1,748 after management, $1,648 after repairs
+    let first = &duplex.rows[0];
+    assert_close(first.rental_income, 1648.0);
+    assert_close(first.actual_payment, owner_only.rows[0].actual_payment - 1648.0);
+    assert_close(first.waste_cost, owner_only.rows[0].waste_cost - 1648.0);
+    assert_close(first.required_payment, owner_only.rows[0].required_payment);
+    assert_close(first.debt, owner_only.rows[0].debt);
+}
+
+#[test]
+fn rent_grows_on_anniversaries_and_repairs_with_inflation() {
+    let schedule = simulate(&Scenario {
+        rental_units: vec![duplex_unit()],
+        inflation_rate: 0.02,
+        ..flat_scenario()
+    });
+
+    assert_close(schedule.rows[11].rental_income, 1648.0);
+    assert_close(schedule.rows[12].rental_income, 2060.0 * 0.95 * 0.92 - 102.0);
+
+    let total: f64 = schedule.rows.iter().map(|row| row.rental_income).sum();
+    assert_close(schedule.summary.total_rental_income, total);
+}
+
+#[test]
+fn each_unit_adds_its_own_rent() {
+    let one = simulate(&Scenario {
+        rental_units: vec![duplex_unit()],
+        ..flat_scenario()
+    });
+    let fourplex = simulate(&Scenario {
+        rental_units: vec![duplex_unit(); 3],
+        ..flat_scenario()
+    });
+
+    assert_close(fourplex.rows[0].rental_income, 3.0 * one.rows[0].rental_income);
+    assert_close(
+        fourplex.summary.total_waste_cost,
+        one.summary.total_waste_cost - 2.0 * one.summary.total_rental_income,
+    );
+}
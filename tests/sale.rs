@@ -0,0 +1,93 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{analyze_sale, irr, simulate, FilingStatus, SaleAssumptions, Scenario, TaxProfile};
+
+#[test]
+fn irr_of_simple_cash_flows() {
+    assert!((irr(&[-100.0, 110.0]).unwrap() - 0.10).abs() < 1e-9);
+    assert!((irr(&[-100.0, 0.0, 121.0]).unwrap() - 0.10).abs() < 1e-9);
+    assert!((irr(&[-100.0, 50.0]).unwrap() + 0.50).abs() < 1e-9);
+    assert_eq!(irr(&[-100.0, -10.0]), None);
+}
+
+#[test]
+fn sale_nets_selling_costs_and_the_remaining_debt() {
+    let scenario = flat_scenario();
+    let schedule = simulate(&scenario);
+    let sale = analyze_sale(&scenario, &schedule, &SaleAssumptions::default()).unwrap();
+
+    // Selling the $500k home after seven years costs 6% commission and 1% transfer tax
+    let debt = schedule.rows[83].debt;
+    assert_eq!(sale.month, 84);
+    assert_close(sale.commission, 30_000.0);
+    assert_close(sale.transfer_taxes, 5000.0);
+    assert_close(sale.remaining_debt, debt);
+    assert_close(sale.net_proceeds, 465_000.0 - debt);
+
+    let outlays: f64 = schedule.rows[..84].iter().map(|row| row.actual_payment).sum();
+    assert_close(sale.total_cash_invested, 100_000.0 + outlays);
+    assert_close(sale.total_return, sale.net_proceeds - sale.total_cash_invested);
+}
+
+#[test]
+fn irr_discounts_the_monthly_cash_flows_to_zero() {
+    let scenario = Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    };
+    let schedule = simulate(&scenario);
+    let sale = analyze_sale(&scenario, &schedule, &SaleAssumptions::default()).unwrap();
+
+    let monthly = (1.0 + sale.irr.unwrap()).powf(1.0 / 12.0) - 1.0;
+    let discount = |month: usize| (1.0 + monthly).powi(month as i32);
+    let npv = -100_000.0
+        + schedule.rows[..84]
+            .iter()
+            .enumerate()
+            .map(|(index, row)| -row.actual_payment / discount(index + 1))
+            .sum::<f64>()
+        + sale.net_proceeds / discount(84);
+    assert_close(npv, 0.0);
+
+    // Every dollar of taxes, insurance and upkeep is an outlay, so seven years at 3%
+    // appreciation loses money
+    assert!((sale.irr.unwrap() + 0.1820).abs() < 1e-4);
+}
+
+#[test]
+fn no_sale_outside_the_schedule() {
+    let scenario = flat_scenario();
+    let schedule = simulate(&scenario);
+    let sale = |holding_months| SaleAssumptions {
+        holding_months,
+        ..SaleAssumptions::default()
+    };
+
+    assert!(analyze_sale(&scenario, &schedule, &sale(0)).is_none());
+    assert!(analyze_sale(&scenario, &schedule, &sale(361)).is_none());
+    assert!(analyze_sale(&scenario, &schedule, &sale(360)).is_some());
+}
+
+#[test]
+fn tax_benefit_reduces_the_cash_invested() {
+    let untaxed = Scenario {
+        house_value: 800_000.0,
+        appreciation_rate: 0.0,
+        ..Scenario::default()
+    };
+    let itemized = Scenario {
+        tax: Some(TaxProfile::new(FilingStatus::Single, 0.24)),
+        ..untaxed.clone()
+    };
+    let sale = SaleAssumptions::default();
+    let before_tax = analyze_sale(&untaxed, &simulate(&untaxed), &sale).unwrap();
+    let schedule = simulate(&itemized);
+    let after_tax = analyze_sale(&itemized, &schedule, &sale).unwrap();
+
+    let tax_saved: f64 = schedule.rows[..84].iter().map(|row| row.tax_benefit).sum();
+    assert!(tax_saved > 0.0);
+    assert_close(after_tax.total_cash_invested, before_tax.total_cash_invested - tax_saved);
+    assert_close(after_tax.total_return, before_tax.total_return + tax_saved);
+    assert!(after_tax.irr.unwrap() > before_tax.irr.unwrap());
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{simulate, LoanTerm, Scenario};
+
+#[test]
+fn first_month_equity_includes_that_months_principal() {
+    let schedule = simulate(&flat_scenario());
+    let row = &schedule.rows[0];
+
+    assert_close(row.interest, 2166.67);
+    assert_close(row.principal, 361.60);
+    assert_close(row.debt, 399_638.40);
+    assert_close(row.equity, 100_361.60);
+    assert_close(row.ltv, 0.7993);
+    assert_close(row.combined_ltv, row.ltv);
+}
+
+#[test]
+fn every_row_equity_and_ltv_match_the_balance_after_payment() {
+    let schedule = simulate(&Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    });
+
+    for row in &schedule.rows {
+        assert_close(row.equity, row.house_cost - row.debt);
+        assert_close(row.ltv * row.house_cost, row.debt);
+    }
+}
+
+#[test]
+fn equity_at_a_horizon() {
+    let schedule = simulate(&flat_scenario());
+    let snapshot = schedule.equity_at(120).unwrap();
+
+    assert_eq!(snapshot.month, 120);
+    assert_close(snapshot.debt, 339_104.93);
+    assert_close(snapshot.equity, 160_895.07);
+    assert_close(snapshot.ltv, 0.6782);
+    assert!(schedule.equity_at(0).is_none());
+    assert!(schedule.equity_at(361).is_none());
+}
+
+#[test]
+fn anniversaries_follow_appreciation_and_amortization() {
+    let schedule = simulate(&Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    });
+    let anniversaries = &schedule.summary.anniversaries;
+
+    assert_eq!(anniversaries.len(), 30);
+    assert!(anniversaries.iter().enumerate().all(|(i, a)| a.month == (i as u32 + 1) * 12));
+    assert_close(anniversaries[4].house_value, 580_808.39);
+    assert_close(anniversaries[4].debt, 374_444.08);
+    assert_close(anniversaries[4].equity, 206_364.31);
+    assert_close(anniversaries[4].ltv, 0.6447);
+}
+
+#[test]
+fn payoff_totals() {
+    let schedule = simulate(&flat_scenario());
+    let summary = &schedule.summary;
+
+    assert_eq!(summary.months_to_payoff, 360);
+    assert_close(summary.total_interest_paid, 510_179.81);
+    assert_close(summary.total_principal_paid, 400_000.0);
+    assert_close(summary.final_equity, summary.final_house_value - schedule.rows.last().unwrap().debt);
+    assert_close(summary.final_equity, 500_000.0);
+}
+
+#[test]
+fn schedule_runs_for_the_full_loan_term() {
+    let schedule = simulate(&Scenario {
+        house_value: 500_000.0,
+        loan_term: LoanTerm::Years(40.0),
+        ..Scenario::default()
+    });
+
+    assert_eq!(schedule.rows.len(), 480);
+    assert_eq!(schedule.summary.anniversaries.len(), 40);
+    assert_close(schedule.rows.last().unwrap().debt, 0.0);
+    assert_close(schedule.summary.balloon_payment, 0.0);
+}
+
+#[test]
+fn cost_of_capital_uses_the_after_tax_alternative_return() {
+    let schedule = simulate(&Scenario {
+        investment_return: 0.07,
+        investment_tax_rate: 0.25,
+        ..flat_scenario()
+    });
+
+    // $100k of equity at 5.25% after tax, on the balance owed going into each month
+    assert_close(schedule.rows[0].cost_of_capital, 100_000.0 * 0.0525 / 12.0);
+    assert_close(schedule.rows[1].cost_of_capital, (500_000.0 - schedule.rows[0].debt) * 0.0525 / 12.0);
+
+    // The loan's rate has no bearing on what the equity could earn elsewhere
+    let cheaper_loan = simulate(&Scenario {
+        interest_rate: 0.03,
+        ..flat_scenario()
+    });
+    assert_close(cheaper_loan.rows[0].cost_of_capital, 100_000.0 * 0.07 / 12.0);
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{analyze_sensitivity, simulate, Scenario, SensitivityMetric};
+
+fn scenario() -> Scenario {
+    Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn base_outcome_matches_the_schedule() {
+    let analysis = analyze_sensitivity(&scenario(), 0.10);
+    let schedule = simulate(&scenario());
+
+    assert_close(analysis.base.total_waste_cost, schedule.summary.total_after_tax_waste_cost);
+    assert_close(analysis.base.monthly_payment, schedule.rows[0].actual_payment);
+    assert_close(analysis.base.final_equity, schedule.summary.final_equity);
+}
+
+#[test]
+fn zero_inputs_are_left_out() {
+    let analysis = analyze_sensitivity(&scenario(), 0.10);
+    let inputs: Vec<_> = analysis.inputs.iter().map(|input| input.input.as_str()).collect();
+
+    assert!(inputs.contains(&"Interest Rate"));
+    assert!(!inputs.contains(&"HOA Fee"));
+    assert!(!inputs.contains(&"Extra Principal"));
+}
+
+#[test]
+fn interest_rate_moves_the_payment_both_ways() {
+    let analysis = analyze_sensitivity(&scenario(), 0.10);
+    let rate = analysis.inputs.iter().find(|input| input.input == "Interest Rate").unwrap();
+
+    let metric = SensitivityMetric::MonthlyPayment;
+    assert!(rate.low.metric(metric) < analysis.base.metric(metric));
+    assert!(rate.high.metric(metric) > analysis.base.metric(metric));
+    assert_close(rate.swing(metric), rate.high.monthly_payment - rate.low.monthly_payment);
+}
+
+#[test]
+fn ranking_orders_by_swing() {
+    let analysis = analyze_sensitivity(&scenario(), 0.10);
+
+    for metric in SensitivityMetric::ALL {
+        let ranked = analysis.ranked(metric);
+        assert_eq!(ranked.len(), analysis.inputs.len());
+        assert!(ranked.windows(2).all(|pair| pair[0].swing(metric) >= pair[1].swing(metric)));
+    }
+    assert_eq!(analysis.ranked(SensitivityMetric::FinalEquity)[0].input, "House Value");
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::{assert_close, flat_scenario};
+use homebuyer::{simulate, InvalidScenario, LoanTerm, PaymentFrequency, Scenario};
+
+#[test]
+fn interest_only_period_then_amortizes_over_the_rest_of_the_term() {
+    let schedule = simulate(&Scenario {
+        interest_only_months: 120,
+        ..flat_scenario()
+    });
+
+    // $400,000 at 6.5% is $2,166.67 a month of interest with no principal
+    for row in &schedule.rows[..120] {
+        assert_close(row.required_payment, 2166.67);
+        assert_close(row.principal, 0.0);
+        assert_close(row.debt, 400_000.0);
+    }
+
+    // Then the full balance amortizes over the remaining 20 years
+    assert_close(schedule.rows[120].required_payment, 2982.29);
+    assert_eq!(schedule.summary.months_to_payoff, 360);
+    assert_close(schedule.summary.balloon_payment, 0.0);
+    assert_eq!(schedule.summary.balloon_month, None);
+    assert_close(schedule.rows.last().unwrap().debt, 0.0);
+}
+
+#[test]
+fn balloon_loan_falls_due_before_the_amortization_ends() {
+    let base = simulate(&flat_scenario());
+    let schedule = simulate(&Scenario {
+        balloon_month: Some(84),
+        ..flat_scenario()
+    });
+
+    // Same payments as the 30-year loan until the balance falls due in month 84
+    assert_eq!(schedule.rows.len(), 84);
+    assert_close(schedule.rows[0].required_payment, base.rows[0].required_payment);
+    assert_close(schedule.rows[83].balloon, schedule.rows[82].debt - schedule.rows[83].principal);
+    assert_close(schedule.summary.balloon_payment, schedule.rows[83].balloon);
+    assert_eq!(schedule.summary.balloon_month, Some(84));
+    assert_eq!(schedule.summary.months_to_payoff, 84);
+    assert_close(schedule.rows[83].debt, 0.0);
+}
+
+#[test]
+fn interest_only_for_the_whole_term_leaves_the_loan_as_a_balloon() {
+    let schedule = simulate(&Scenario {
+        interest_only_months: 360,
+        ..flat_scenario()
+    });
+
+    assert_close(schedule.summary.total_interest_paid, 360.0 * 2166.67);
+    assert_close(schedule.summary.balloon_payment, 400_000.0);
+    assert_eq!(schedule.summary.balloon_month, Some(360));
+}
+
+#[test]
+fn interest_only_accrues_per_period_at_other_frequencies() {
+    let schedule = simulate(&Scenario {
+        interest_only_months: 12,
+        payment_frequency: PaymentFrequency::Biweekly,
+        ..flat_scenario()
+    });
+
+    // 26 interest-only payments of $1,000 leave the balance untouched for a year
+    let first_year: f64 = schedule.rows[..12].iter().map(|row| row.required_payment).sum();
+    assert_close(first_year, 26_000.0);
+    assert_close(schedule.rows[11].debt, 400_000.0);
+    assert!(schedule.rows[12].principal > 0.0);
+    assert_close(schedule.summary.balloon_payment, 0.0);
+}
+
+#[test]
+fn balloon_on_a_zero_month_term_gives_an_empty_schedule() {
+    let schedule = simulate(&Scenario {
+        loan_term: LoanTerm::Months(0),
+        balloon_month: Some(84),
+        ..flat_scenario()
+    });
+
+    assert!(schedule.rows.is_empty());
+    assert_eq!(schedule.summary.balloon_month, None);
+}
+
+#[test]
+fn balloon_at_or_after_the_term_is_no_balloon() {
+    let base = simulate(&flat_scenario());
+    for month in [360, 400] {
+        let schedule = simulate(&Scenario {
+            balloon_month: Some(month),
+            ..flat_scenario()
+        });
+        assert_eq!(schedule.rows.len(), 360);
+        assert_close(schedule.summary.balloon_payment, 0.0);
+        assert_close(schedule.summary.total_interest_paid, base.summary.total_interest_paid);
+    }
+}
+
+#[test]
+fn zero_term_and_zero_balloon_month_are_invalid() {
+    assert_eq!(flat_scenario().validate(), Ok(()));
+    let zero_term = Scenario {
+        loan_term: LoanTerm::Months(0),
+        ..flat_scenario()
+    };
+    assert_eq!(zero_term.validate(), Err(InvalidScenario::ZeroLoanTerm));
+    let zero_balloon = Scenario {
+        balloon_month: Some(0),
+        ..flat_scenario()
+    };
+    assert_eq!(zero_balloon.validate(), Err(InvalidScenario::ZeroBalloonMonth));
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::assert_close;
+use homebuyer::{simulate, FilingStatus, Prepayment, Scenario, TaxProfile};
+
+#[test]
+fn benefit_is_only_the_itemized_excess_over_the_standard_deduction() {
+    let profile = TaxProfile::new(FilingStatus::Single, 0.24);
+
+    assert_close(profile.annual_benefit(20_000.0, 8_000.0, 300_000.0), (28_000.0 - 16_100.0) * 0.24);
+    assert_close(profile.annual_benefit(5_000.0, 4_000.0, 100_000.0), 0.0);
+}
+
+#[test]
+fn salt_cap_and_debt_limit_shrink_the_deduction() {
+    let profile = TaxProfile {
+        state_income_tax: 30_000.0,
+        ..TaxProfile::new(FilingStatus::MarriedFilingJointly, 0.35)
+    };
+
+    // Half the interest is deductible and property tax only fills the rest of the SALT cap.
+    let with_home = 30_000.0 + 40_400.0;
+    let without_home = 32_200.0;
+    assert_close(
+        profile.annual_benefit(60_000.0, 20_000.0, 1_500_000.0),
+        (with_home - without_home) * 0.35,
+    );
+}
+
+#[test]
+fn after_tax_waste_cost_spreads_each_years_benefit_over_its_months() {
+    let schedule = simulate(&Scenario {
+        house_value: 800_000.0,
+        appreciation_rate: 0.0,
+        tax: Some(TaxProfile::new(FilingStatus::Single, 0.24)),
+        ..Scenario::default()
+    });
+
+    let first_year = &schedule.rows[..12];
+    let interest: f64 = first_year.iter().map(|row| row.interest).sum();
+    let taxes: f64 = first_year.iter().map(|row| row.taxes).sum();
+    let expected = (interest + taxes - 16_100.0) * 0.24 / 12.0;
+    for row in first_year {
+        assert_close(row.tax_benefit, expected);
+        assert_close(row.after_tax_waste_cost, row.waste_cost - expected);
+    }
+
+    let summary = &schedule.summary;
+    assert_close(summary.total_after_tax_waste_cost, summary.total_waste_cost - summary.total_tax_benefit);
+}
+
+#[test]
+fn no_tax_profile_leaves_waste_cost_unchanged() {
+    let schedule = simulate(&Scenario {
+        house_value: 500_000.0,
+        ..Scenario::default()
+    });
+
+    assert!(schedule.rows.iter().all(|row| row.tax_benefit == 0.0 && row.after_tax_waste_cost == row.waste_cost));
+}
+
+#[test]
+fn debt_limit_proration_uses_the_balance_before_prepayments() {
+    let profile = TaxProfile::new(FilingStatus::Single, 0.24);
+    let schedule = simulate(&Scenario {
+        house_value: 1_500_000.0,
+        appreciation_rate: 0.0,
+        prepayments: vec![Prepayment::once(1, 200_000.0)],
+        tax: Some(profile.clone()),
+        ..Scenario::default()
+    });
+
+    // The $1.2M loan is owed in full through the first month, then $200k less
+    let first_year = &schedule.rows[..12];
+    let opening_balances: f64 = 1_200_000.0 + first_year[..11].iter().map(|row| row.debt).sum::<f64>();
+    let interest: f64 = first_year.iter().map(|row| row.interest).sum();
+    let taxes: f64 = first_year.iter().map(|row| row.taxes).sum();
+    let expected = profile.annual_benefit(interest, taxes, opening_balances / 12.0) / 12.0;
+    assert_close(first_year[0].tax_benefit, expected);
+}